@@ -0,0 +1,34 @@
+//! Compares wrapping and consuming a value against using the raw value.
+//!
+//! The [`panic!`]ing drop paths are `#[cold]` and outlined, so a wrapper that is always consumed
+//! should cost about the same as the raw value. Benchmarks build without `debug_assertions`, so the
+//! `dbg` types are the passthrough wrappers, which should cost exactly the same as the raw value.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use no_drop::dbg::{NoDrop as NoDropDbg, NoDropMsg as NoDropMsgDbg};
+use no_drop::rls::{NoDrop, NoDropMsg};
+
+fn wrap_consume_u64(c: &mut Criterion) {
+    let mut group = c.benchmark_group("wrap_consume_u64");
+    group.bench_function("raw", |b| b.iter(|| black_box(42_u64)));
+    group.bench_function("no_drop", |b| b.iter(|| NoDrop::wrap(black_box(42_u64)).consume()));
+    group.bench_function("no_drop_msg", |b| b.iter(|| NoDropMsg::wrap(black_box(42_u64), "not consumed").consume()));
+    group.bench_function("passthrough", |b| b.iter(|| NoDropDbg::wrap(black_box(42_u64)).consume()));
+    group.bench_function("passthrough_msg", |b| {
+        b.iter(|| NoDropMsgDbg::wrap(black_box(42_u64), "not consumed").consume());
+    });
+    group.finish();
+}
+
+fn wrap_consume_vec(c: &mut Criterion) {
+    let mut group = c.benchmark_group("wrap_consume_vec");
+    group.bench_function("raw", |b| b.iter(|| black_box(vec![1_u8, 2, 3]).len()));
+    group.bench_function("no_drop", |b| b.iter(|| NoDrop::wrap(black_box(vec![1_u8, 2, 3])).consume().len()));
+    group.bench_function("passthrough", |b| b.iter(|| NoDropDbg::wrap(black_box(vec![1_u8, 2, 3])).consume().len()));
+    group.finish();
+}
+
+criterion_group!(benches, wrap_consume_u64, wrap_consume_vec);
+criterion_main!(benches);
@@ -0,0 +1,194 @@
+//! Derive and attribute macros for the [`no_drop`](https://docs.rs/no_drop) crate.
+//!
+//! These are re-exported from `no_drop` behind its `derive` feature, and should be used from there.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote, ToTokens};
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Error, Index, Member};
+
+/// Derives a `consume(self)` method and a [`panic!`]ing [`Drop`] impl for a struct.
+///
+/// The generated `consume` moves every field out of the struct, returning them as a tuple in
+/// declaration order, without running the struct's [`Drop`]. Dropping the struct without calling
+/// `consume` [`panic!`]s with `"<Type> was dropped without being consumed"`.
+///
+/// Only structs are supported. Because the struct implements [`Drop`], its fields cannot be moved
+/// out by destructuring; `consume` is the only way to take them.
+#[proc_macro_derive(NoDropConsume)]
+pub fn derive_no_drop_consume(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_no_drop_consume(&input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn expand_no_drop_consume(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(input, "NoDropConsume can only be derived for structs"));
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let msg = format!("{name} was dropped without being consumed");
+
+    let types = data.fields.iter().map(|field| &field.ty);
+    let members = data.fields.iter().enumerate().map(|(index, field)| match &field.ident {
+        Some(ident) => quote!(#ident),
+        None => Index::from(index).into_token_stream(),
+    });
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Consumes the value, returning its fields in declaration order.
+            #[allow(clippy::unused_unit, unused_unsafe)]
+            pub fn consume(self) -> (#(#types,)*) {
+                let this = ::core::mem::ManuallyDrop::new(self);
+                // SAFETY: `this` is never dropped, so each field is read out exactly once.
+                unsafe { (#(::core::ptr::read(&this.#members),)*) }
+            }
+        }
+
+        impl #impl_generics ::core::ops::Drop for #name #ty_generics #where_clause {
+            fn drop(&mut self) {
+                ::no_drop::__private::panic_unconsumed(#msg);
+            }
+        }
+    })
+}
+
+/// Guards the fields of a struct marked `#[must_consume]`, so they must be consumed.
+///
+/// Each marked field's type `T` is replaced with `::no_drop::rls::NoDrop<T>`, so dropping the
+/// struct without consuming it [`panic!`]s when the first marked field is dropped. Unmarked fields
+/// are left as is. The struct does not implement [`Drop`] itself. Two methods are generated, with
+/// the struct's visibility:
+///
+/// - `new`, taking every field's original type in declaration order, and wrapping the marked
+///   ones. The [`panic!`] reports the location `new` was called from.
+/// - `consume(self)`, destructuring the struct and returning every field in declaration order,
+///   with the marked fields consumed.
+///
+/// For example, this struct with two marked fields and one normal field:
+///
+/// ```rust,ignore
+/// #[must_consume]
+/// pub struct Transaction {
+///     #[must_consume]
+///     commit: Commit,
+///     #[must_consume]
+///     log: Log,
+///     retries: u32,
+/// }
+/// ```
+///
+/// Expands to:
+///
+/// ```rust,ignore
+/// pub struct Transaction {
+///     commit: ::no_drop::rls::NoDrop<Commit>,
+///     log: ::no_drop::rls::NoDrop<Log>,
+///     retries: u32,
+/// }
+///
+/// impl Transaction {
+///     #[track_caller]
+///     pub fn new(commit: Commit, log: Log, retries: u32) -> Self {
+///         Self {
+///             commit: ::no_drop::rls::NoDrop::wrap(commit),
+///             log: ::no_drop::rls::NoDrop::wrap(log),
+///             retries,
+///         }
+///     }
+///
+///     pub fn consume(self) -> (Commit, Log, u32) {
+///         let Self { commit, log, retries } = self;
+///         (commit.consume(), log.consume(), retries)
+///     }
+/// }
+/// ```
+///
+/// Only structs with at least one marked field are supported.
+#[proc_macro_attribute]
+pub fn must_consume(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = TokenStream2::from(args);
+    let mut input = parse_macro_input!(input as DeriveInput);
+    if !args.is_empty() {
+        return Error::new_spanned(args, "must_consume takes no arguments").into_compile_error().into();
+    }
+    expand_must_consume(&mut input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn expand_must_consume(input: &mut DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &mut input.data else {
+        return Err(Error::new_spanned(&input.ident, "must_consume can only be applied to structs"));
+    };
+
+    let mut members = Vec::new();
+    let mut patterns = Vec::new();
+    let mut params = Vec::new();
+    let mut types = Vec::new();
+    let mut inits = Vec::new();
+    let mut outputs = Vec::new();
+    let mut any_marked = false;
+    for (index, field) in data.fields.iter_mut().enumerate() {
+        let attrs = field.attrs.len();
+        field.attrs.retain(|attr| !attr.path().is_ident("must_consume"));
+        let marked = field.attrs.len() != attrs;
+        any_marked |= marked;
+
+        let param = field.ident.clone().unwrap_or_else(|| format_ident!("field{index}"));
+        let ty = field.ty.clone();
+        match marked {
+            true => {
+                field.ty = parse_quote!(::no_drop::rls::NoDrop<#ty>);
+                inits.push(quote!(::no_drop::rls::NoDrop::wrap(#param)));
+                outputs.push(quote!(#param.consume()));
+            }
+            false => {
+                inits.push(quote!(#param));
+                outputs.push(quote!(#param));
+            }
+        }
+        patterns.push(match &field.ident {
+            Some(ident) => quote!(#ident),
+            None => {
+                let index = Index::from(index);
+                quote!(#index: #param)
+            }
+        });
+        members.push(field.ident.clone().map_or_else(|| Member::Unnamed(Index::from(index)), Member::Named));
+        params.push(param);
+        types.push(ty);
+    }
+
+    if !any_marked {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "must_consume requires at least one field marked #[must_consume]",
+        ));
+    }
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #input
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Creates a new value, guarding the fields marked `#[must_consume]`.
+            #[track_caller]
+            #[allow(clippy::too_many_arguments)]
+            #[allow(clippy::redundant_field_names)]
+            #vis fn new(#(#params: #types),*) -> Self {
+                Self { #(#members: #inits),* }
+            }
+
+            /// Consumes the value, returning its fields in declaration order.
+            #[allow(clippy::unused_unit)]
+            #vis fn consume(self) -> (#(#types,)*) {
+                let Self { #(#patterns),* } = self;
+                (#(#outputs,)*)
+            }
+        }
+    })
+}
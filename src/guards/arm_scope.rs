@@ -0,0 +1,117 @@
+use core::ops::Deref;
+
+use sealed::sealed;
+
+use super::{drop_guard_pass::DropGuardPass, DropGuard};
+use crate::markers::Msg;
+
+/// A guard that can be armed for the duration of an [`ArmScope`].
+///
+/// This trait is sealed and cannot be implemented outside this crate.
+#[sealed]
+pub trait ScopedGuard {
+    /// Arms the guard, returning whether it was disarmed.
+    #[track_caller]
+    fn arm(&mut self) -> bool;
+
+    /// Disarms the guard, returning whether it was armed.
+    fn disarm(&mut self) -> bool;
+}
+
+#[sealed]
+impl ScopedGuard for DropGuard<'_> {
+    #[track_caller]
+    fn arm(&mut self) -> bool {
+        DropGuard::arm(self)
+    }
+
+    fn disarm(&mut self) -> bool {
+        DropGuard::disarm(self)
+    }
+}
+
+#[sealed]
+impl ScopedGuard for DropGuardPass<'_, Msg> {
+    fn arm(&mut self) -> bool {
+        DropGuardPass::arm(self)
+    }
+
+    fn disarm(&mut self) -> bool {
+        DropGuardPass::disarm(self)
+    }
+}
+
+/// Keeps a guard armed until the end of a scope, then disarms it.
+///
+/// Created by [`DropGuard::arm_scope`]. The guard is disarmed when this is dropped, whether the
+/// scope ends normally, by an early return, or by a [`panic!`]. This never [`panic!`]s itself.
+///
+/// The guard can be inspected through [`Deref`] while the scope is alive.
+#[derive(Debug)]
+#[must_use = "the guard is disarmed as soon as the `ArmScope` is dropped"]
+pub struct ArmScope<'scope, G: ScopedGuard>(&'scope mut G);
+
+impl<'scope, G: ScopedGuard> ArmScope<'scope, G> {
+    /// Arms `guard` until the returned scope is dropped.
+    #[track_caller]
+    pub(crate) fn new(guard: &'scope mut G) -> Self {
+        guard.arm();
+        Self(guard)
+    }
+}
+
+impl<G: ScopedGuard> Deref for ArmScope<'_, G> {
+    type Target = G;
+
+    fn deref(&self) -> &G {
+        self.0
+    }
+}
+
+impl<G: ScopedGuard> Drop for ArmScope<'_, G> {
+    fn drop(&mut self) {
+        self.0.disarm();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use super::*;
+
+    #[test]
+    fn disarmed_after_scope() {
+        let mut guard = DropGuard::new_disarmed("not released");
+        {
+            let scope = guard.arm_scope();
+            assert!(scope.armed());
+        }
+        assert!(guard.disarmed());
+    }
+
+    #[test]
+    fn disarmed_after_panic_in_scope() {
+        let mut guard = DropGuard::new_disarmed("not released");
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let _scope = guard.arm_scope();
+            panic!("failed inside scope");
+        }));
+        assert!(result.is_err());
+        assert!(guard.disarmed());
+    }
+
+    #[test]
+    fn disarms_guard_armed_before_scope() {
+        let mut guard = DropGuard::new_armed("not released");
+        drop(guard.arm_scope());
+        assert!(guard.disarmed());
+    }
+
+    #[test]
+    fn passthrough_scope() {
+        let mut guard = crate::guards::DropGuardPassthroughMsg::new_disarmed("not released");
+        assert!(guard.arm_scope().armed());
+        assert!(guard.disarmed());
+    }
+}
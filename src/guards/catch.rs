@@ -0,0 +1,93 @@
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::string::String;
+
+use crate::no_drop::take_last_report;
+
+/// A guard was dropped without being consumed, caught by [`catch_unconsumed`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{0}")]
+pub struct GuardLeaked(String);
+
+impl GuardLeaked {
+    /// Returns the message the guard [`panic!`]ed with, including where it was created.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Runs `body`, returning [`GuardLeaked`] instead of unwinding if a guard [`panic!`]s on drop.
+///
+/// This lets a library boundary detect a forgotten guard without crashing. Any other [`panic!`]
+/// from `body` is resumed unchanged. The guard [`panic!`] is still reported by the panic hook,
+/// and the aborting guards cannot be caught.
+///
+/// As with [`catch_unwind`], any state `body` was modifying may be left inconsistent when an error
+/// is returned.
+///
+/// # Errors
+///
+/// Returns [`GuardLeaked`] with the guard's message if a guard was dropped while armed.
+///
+/// # Examples
+///
+/// ```rust
+/// use no_drop::rls::{catch_unconsumed, NoDropMsg};
+///
+/// let leaked = catch_unconsumed(|| drop(NoDropMsg::wrap(42, "forgot to consume")));
+/// assert!(leaked.unwrap_err().message().starts_with("forgot to consume"));
+///
+/// let consumed = catch_unconsumed(|| NoDropMsg::wrap(42, "consumed").consume());
+/// assert_eq!(consumed, Ok(42));
+/// ```
+pub fn catch_unconsumed<R>(body: impl FnOnce() -> R) -> Result<R, GuardLeaked> {
+    take_last_report();
+    let payload = match catch_unwind(AssertUnwindSafe(body)) {
+        Ok(value) => return Ok(value),
+        Err(payload) => payload,
+    };
+
+    let msg = payload.downcast_ref::<String>().map(String::as_str).or_else(|| payload.downcast_ref::<&str>().copied());
+    match take_last_report() {
+        Some(report) if msg == Some(report.as_str()) => Err(GuardLeaked(report)),
+        _ => resume_unwind(payload),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::no_drop::{NoDrop, NoDropMsg};
+
+    #[test]
+    fn forgotten_msg_is_caught() {
+        let leaked = catch_unconsumed(|| drop(NoDropMsg::wrap(42, "custom message"))).unwrap_err();
+        assert!(leaked.message().starts_with("custom message (created at "), "{leaked}");
+    }
+
+    #[test]
+    fn forgotten_empty_is_caught() {
+        let leaked = catch_unconsumed(|| drop(NoDrop::wrap(42))).unwrap_err();
+        assert!(leaked.message().contains("(created at "), "{leaked}");
+    }
+
+    #[test]
+    fn consumed_is_ok() {
+        assert_eq!(catch_unconsumed(|| NoDropMsg::wrap(42, "custom message").consume()), Ok(42));
+    }
+
+    #[test]
+    #[should_panic(expected = "unrelated")]
+    fn other_panics_resume() {
+        let _ = catch_unconsumed(|| panic!("unrelated"));
+    }
+
+    #[test]
+    #[should_panic(expected = "unrelated")]
+    fn caught_guard_panic_is_not_reused() {
+        let _ = catch_unconsumed(|| {
+            let _ = catch_unwind(|| drop(NoDrop::wrap(42)));
+            panic!("unrelated");
+        });
+    }
+}
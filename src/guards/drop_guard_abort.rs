@@ -0,0 +1,228 @@
+use alloc::borrow::Cow;
+
+use crate::{
+    guards::GuardError,
+    no_drop::{NoDropAbortEmpty, NoDropAbortMsg},
+};
+
+/// A mutable drop guard that aborts the process if dropped while armed.
+///
+/// This guard can be toggled between [`Self::armed`] and [`Self::disarmed`] states via
+/// [`Self::arm`] and [`Self::disarm`], respectively. While [`Self::armed`] it will abort the
+/// process if dropped, when [`Self::disarmed`] it will not.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DropGuardAbortEmpty(Option<NoDropAbortEmpty>);
+
+impl DropGuardAbortEmpty {
+    /// Creates a new armed guard.
+    #[must_use]
+    #[track_caller]
+    pub fn new_armed() -> Self {
+        Self(Some(NoDropAbortEmpty::new()))
+    }
+
+    /// Creates a new disarmed guard.
+    #[must_use]
+    pub fn new_disarmed() -> Self {
+        Self(None)
+    }
+
+    /// Returns whether the guard is armed.
+    #[must_use]
+    pub fn armed(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// Returns whether the guard is disarmed.
+    #[must_use]
+    pub fn disarmed(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// Arms the guard.
+    ///
+    /// Returns `true` if the guard was armed, or `false` if it was already armed.
+    #[track_caller]
+    pub fn arm(&mut self) -> bool {
+        self.0.replace(NoDropAbortEmpty::new()).map(NoDropAbortEmpty::forget).is_none()
+    }
+
+    /// Disarms the guard.
+    ///
+    /// Returns `true` if the guard was disarmed or `false` if it was already disarmed.
+    pub fn disarm(&mut self) -> bool {
+        self.0.take().map(NoDropAbortEmpty::forget).is_some()
+    }
+
+    /// Consumes the guard, returning the inner [`NoDropAbortEmpty`] if armed, or [`None`] if
+    /// disarmed.
+    #[must_use]
+    pub fn into_guard(self) -> Option<NoDropAbortEmpty> {
+        self.0
+    }
+}
+
+impl From<NoDropAbortEmpty> for DropGuardAbortEmpty {
+    fn from(no_drop: NoDropAbortEmpty) -> Self {
+        Self(Some(no_drop))
+    }
+}
+
+impl TryFrom<DropGuardAbortEmpty> for NoDropAbortEmpty {
+    type Error = GuardError;
+
+    fn try_from(value: DropGuardAbortEmpty) -> Result<Self, Self::Error> {
+        value.into_guard().ok_or(GuardError::NotArmed)
+    }
+}
+
+/// A mutable drop guard with a custom message that aborts the process if dropped while armed.
+///
+/// This guard can be toggled between [`Self::armed`] and [`Self::disarmed`] states via
+/// [`Self::arm`] and [`Self::disarm`], respectively. While [`Self::armed`] it will print the
+/// custom message and abort the process if dropped, when [`Self::disarmed`] it will not.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DropGuardAbortMsg<'msg>(DropGuardAbortMsgState<'msg>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DropGuardAbortMsgState<'msg> {
+    Armed(NoDropAbortMsg<'msg>),
+    Disarmed(Cow<'msg, str>),
+}
+
+impl Default for DropGuardAbortMsgState<'_> {
+    fn default() -> Self {
+        Self::Disarmed(Cow::Borrowed(""))
+    }
+}
+
+impl<'msg> DropGuardAbortMsg<'msg> {
+    /// Creates a new armed guard with a custom abort message.
+    #[must_use]
+    #[track_caller]
+    pub fn new_armed<M: Into<Cow<'msg, str>>>(msg: M) -> Self {
+        Self(DropGuardAbortMsgState::Armed(NoDropAbortMsg::guard(msg)))
+    }
+
+    /// Creates a new disarmed guard with a custom abort message.
+    ///
+    /// The message is retained and will be used if the guard is later armed.
+    #[must_use]
+    pub fn new_disarmed<M: Into<Cow<'msg, str>>>(msg: M) -> Self {
+        Self(DropGuardAbortMsgState::Disarmed(msg.into()))
+    }
+
+    /// Returns whether the guard is armed.
+    #[must_use]
+    pub fn armed(&self) -> bool {
+        matches!(self.0, DropGuardAbortMsgState::Armed(_))
+    }
+
+    /// Returns whether the guard is disarmed.
+    #[must_use]
+    pub fn disarmed(&self) -> bool {
+        matches!(self.0, DropGuardAbortMsgState::Disarmed(_))
+    }
+
+    /// Arms the guard.
+    ///
+    /// Returns `true` if the guard was armed, or `false` if it was already armed.
+    #[track_caller]
+    pub fn arm(&mut self) -> bool {
+        match &mut self.0 {
+            DropGuardAbortMsgState::Armed(_) => false,
+            DropGuardAbortMsgState::Disarmed(msg) => {
+                let msg = core::mem::take(msg);
+                self.0 = DropGuardAbortMsgState::Armed(NoDropAbortMsg::guard(msg));
+                true
+            }
+        }
+    }
+
+    /// Disarms the guard.
+    ///
+    /// Returns `true` if the guard was disarmed or `false` if it was already disarmed.
+    pub fn disarm(&mut self) -> bool {
+        match core::mem::take(&mut self.0) {
+            DropGuardAbortMsgState::Disarmed(msg) => {
+                self.0 = DropGuardAbortMsgState::Disarmed(msg);
+                false
+            }
+            DropGuardAbortMsgState::Armed(guard) => {
+                self.0 = DropGuardAbortMsgState::Disarmed(guard.unwrap_msg());
+                true
+            }
+        }
+    }
+
+    /// Consumes the guard, returning the inner [`NoDropAbortMsg`] if armed, or [`None`] if
+    /// disarmed.
+    #[must_use]
+    pub fn into_guard(self) -> Option<NoDropAbortMsg<'msg>> {
+        match self.0 {
+            DropGuardAbortMsgState::Armed(guard) => Some(guard),
+            DropGuardAbortMsgState::Disarmed(_) => None,
+        }
+    }
+}
+
+impl<'msg> From<NoDropAbortMsg<'msg>> for DropGuardAbortMsg<'msg> {
+    fn from(no_drop: NoDropAbortMsg<'msg>) -> Self {
+        Self(DropGuardAbortMsgState::Armed(no_drop))
+    }
+}
+
+impl<'msg> TryFrom<DropGuardAbortMsg<'msg>> for NoDropAbortMsg<'msg> {
+    type Error = GuardError;
+
+    fn try_from(value: DropGuardAbortMsg<'msg>) -> Result<Self, Self::Error> {
+        value.into_guard().ok_or(GuardError::NotArmed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::guards::test_macros::{ctor, transition, try_from};
+
+    mod empty {
+        use super::*;
+
+        ctor!(new_disarmed, DropGuardAbortEmpty::new_disarmed, (), disarmed);
+
+        try_from!(try_from_armed, DropGuardAbortEmpty::new_armed, (), NoDropAbortEmpty, armed);
+        try_from!(try_from_disarmed, DropGuardAbortEmpty::new_disarmed, (), NoDropAbortEmpty, disarmed);
+
+        transition!(disarm_when_armed, DropGuardAbortEmpty::new_armed, (), disarm, true, disarmed);
+        transition!(disarm_when_disarmed, DropGuardAbortEmpty::new_disarmed, (), disarm, false, disarmed);
+
+        #[test]
+        fn arm_when_disarmed() {
+            let mut guard = DropGuardAbortEmpty::new_disarmed();
+            assert!(guard.arm());
+            assert!(!guard.arm());
+            assert!(guard.disarm());
+        }
+    }
+
+    mod msg {
+        use super::*;
+
+        ctor!(new_disarmed, DropGuardAbortMsg::new_disarmed, ("message"), disarmed);
+
+        try_from!(try_from_armed, DropGuardAbortMsg::new_armed, ("msg"), NoDropAbortMsg, armed);
+        try_from!(try_from_disarmed, DropGuardAbortMsg::new_disarmed, ("msg"), NoDropAbortMsg, disarmed);
+
+        transition!(disarm_when_armed, DropGuardAbortMsg::new_armed, ("test"), disarm, true, disarmed);
+        transition!(disarm_when_disarmed, DropGuardAbortMsg::new_disarmed, ("test"), disarm, false, disarmed);
+
+        #[test]
+        fn arm_when_disarmed_retains_msg() {
+            let mut guard = DropGuardAbortMsg::new_disarmed("retained");
+            assert!(guard.arm());
+            assert!(!guard.arm());
+            assert!(guard.disarm());
+            assert_eq!(guard.0, DropGuardAbortMsgState::Disarmed(Cow::Borrowed("retained")));
+        }
+    }
+}
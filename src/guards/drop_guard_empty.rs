@@ -1,73 +1,252 @@
-use crate::{guards::GuardNotArmed, no_drop::NoDropEmpty};
+use core::mem;
 
-/// A mutable drop guard.
+#[cfg(feature = "std")]
+use crate::guards::PanicGuard;
+use crate::{guards::GuardError, no_drop::NoDropEmpty};
+
+/// A mutable drop guard, optionally carrying a `T` value.
 ///
 /// This guard can be toggled between [`Self::armed`] and [`Self::disarmed`] states via
 /// [`Self::arm`] and [`Self::disarm`], respectively. While [`Self::armed`] it will [`panic!`]
-/// if dropped, when [`Self::disarmed`] it will not.
+/// if dropped, when [`Self::disarmed`] it will not. The value is retained in both states, and
+/// dropped normally if the guard is dropped while disarmed.
 ///
 /// This can be used to guard a critical state or another type, ensuring it is not dropped while in
 /// that state.
+///
+/// The guard is [`Send`], [`Sync`], and [`Unpin`] whenever `T` is.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct DropGuardEmpty(Option<NoDropEmpty>);
+pub struct DropGuardEmpty<T = ()>(DropGuardEmptyState<T>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DropGuardEmptyState<T> {
+    Armed(NoDropEmpty<T>),
+    Disarmed(T),
+    /// The value was taken by [`DropGuardEmpty::take`], or is being moved between [`Self::Armed`]
+    /// and [`Self::Disarmed`]. A vacant guard is disarmed, and cannot be armed again.
+    Vacant,
+}
 
 impl DropGuardEmpty {
     /// Creates a new armed guard.
     #[must_use]
+    #[track_caller]
     pub fn new_armed() -> Self {
-        Self(Some(NoDropEmpty::new()))
+        Self::wrap_armed(())
     }
 
     /// Creates a new disarmed guard.
     #[must_use]
     pub fn new_disarmed() -> Self {
-        Self(None)
+        Self::wrap_disarmed(())
+    }
+
+    /// Creates a [`PanicGuard`] that runs `cleanup` only if it is dropped while the thread is
+    /// [`panic!`]ing, and does nothing otherwise.
+    #[cfg(feature = "std")]
+    pub const fn panic_only<F: FnOnce()>(cleanup: F) -> PanicGuard<F> {
+        PanicGuard::new(cleanup)
+    }
+}
+
+impl<T> DropGuardEmpty<T> {
+    /// Creates a new armed guard around `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::DropGuardEmpty;
+    ///
+    /// let mut guard = DropGuardEmpty::wrap_armed(vec![1, 2, 3]);
+    /// guard.disarm();
+    /// assert_eq!(guard.consume(), vec![1, 2, 3]);
+    /// ```
+    #[must_use]
+    #[track_caller]
+    pub fn wrap_armed(value: T) -> Self {
+        Self(DropGuardEmptyState::Armed(NoDropEmpty::wrap(value)))
+    }
+
+    /// Creates a new disarmed guard around `value`.
+    #[must_use]
+    pub fn wrap_disarmed(value: T) -> Self {
+        Self(DropGuardEmptyState::Disarmed(value))
     }
 
     /// Returns whether the guard is armed.
     #[must_use]
     pub fn armed(&self) -> bool {
-        self.0.is_some()
+        matches!(self.0, DropGuardEmptyState::Armed(_))
     }
 
     /// Returns whether the guard is disarmed.
     #[must_use]
     pub fn disarmed(&self) -> bool {
-        self.0.is_none()
+        !self.armed()
     }
 
     /// Arms the guard.
     ///
-    /// Returns `true` if the guard was armed, or `false` if it was already armed.
+    /// Returns `true` if the guard was armed, or `false` if it was already armed, or its value was
+    /// taken by [`Self::take`].
+    #[track_caller]
     pub fn arm(&mut self) -> bool {
-        self.0.replace(NoDropEmpty::new()).map(NoDropEmpty::forget).is_none()
+        match mem::replace(&mut self.0, DropGuardEmptyState::Vacant) {
+            DropGuardEmptyState::Disarmed(value) => {
+                self.0 = DropGuardEmptyState::Armed(NoDropEmpty::wrap(value));
+                true
+            }
+            state => {
+                self.0 = state;
+                false
+            }
+        }
     }
 
     /// Disarms the guard.
     ///
     /// Returns `true` if the guard was disarmed or `false` if it was already disarmed.
     pub fn disarm(&mut self) -> bool {
-        self.0.take().map(NoDropEmpty::forget).is_some()
+        match mem::replace(&mut self.0, DropGuardEmptyState::Vacant) {
+            DropGuardEmptyState::Armed(guard) => {
+                self.0 = DropGuardEmptyState::Disarmed(guard.consume());
+                true
+            }
+            state => {
+                self.0 = state;
+                false
+            }
+        }
+    }
+
+    /// Arms the guard if it is disarmed and `pred` returns `true` for the inner value.
+    ///
+    /// Returns `true` if the guard was armed, or `false` if it was already armed or `pred`
+    /// returned `false`. `pred` is only called if the guard is disarmed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::DropGuardEmpty;
+    ///
+    /// let mut guard = DropGuardEmpty::wrap_disarmed(Vec::<i32>::new());
+    /// assert!(!guard.arm_if(|buffer| !buffer.is_empty()));
+    /// assert!(guard.disarmed());
+    /// ```
+    #[track_caller]
+    pub fn arm_if(&mut self, pred: impl FnOnce(&T) -> bool) -> bool {
+        match &self.0 {
+            DropGuardEmptyState::Disarmed(value) if pred(value) => self.arm(),
+            _ => false,
+        }
+    }
+
+    /// Disarms the guard if it is armed and `pred` returns `true` for the inner value.
+    ///
+    /// Returns `true` if the guard was disarmed, or `false` if it was already disarmed or `pred`
+    /// returned `false`. `pred` is only called if the guard is armed.
+    pub fn disarm_if(&mut self, pred: impl FnOnce(&T) -> bool) -> bool {
+        match &self.0 {
+            DropGuardEmptyState::Armed(guard) if pred(guard) => self.disarm(),
+            _ => false,
+        }
+    }
+
+    /// Takes the value out of an armed guard, leaving it disarmed without a value.
+    ///
+    /// Unlike [`Self::disarm`], which keeps the value, the guard no longer holds one afterwards, so
+    /// it cannot be armed again. Returns [`None`], leaving the guard unchanged, if it is disarmed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::DropGuardEmpty;
+    ///
+    /// let mut guard = DropGuardEmpty::wrap_armed(42);
+    /// assert_eq!(guard.take(), Some(42));
+    /// assert!(guard.disarmed());
+    /// assert!(!guard.arm());
+    /// ```
+    pub fn take(&mut self) -> Option<T> {
+        match mem::replace(&mut self.0, DropGuardEmptyState::Vacant) {
+            DropGuardEmptyState::Armed(guard) => Some(guard.consume()),
+            state => {
+                self.0 = state;
+                None
+            }
+        }
+    }
+
+    /// Consumes the guard, returning the inner value whether armed or disarmed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value was taken by [`Self::take`].
+    #[must_use]
+    pub fn consume(self) -> T {
+        self.consume_or_else(|| panic!("{}", GuardError::AlreadyConsumed))
+    }
+
+    /// Consumes the guard, returning the inner value whether armed or disarmed, or `default` if
+    /// the value was taken by [`Self::take`].
+    ///
+    /// Unlike [`Self::consume`], this never [`panic!`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::DropGuardEmpty;
+    ///
+    /// let mut guard = DropGuardEmpty::wrap_armed(42);
+    /// guard.disarm();
+    /// assert_eq!(guard.consume_or(0), 42);
+    /// ```
+    #[must_use]
+    pub fn consume_or(self, default: T) -> T {
+        self.consume_or_else(|| default)
+    }
+
+    /// Consumes the guard, returning the inner value whether armed or disarmed, or the result of
+    /// `f` if the value was taken by [`Self::take`].
+    ///
+    /// Unlike [`Self::consume`], this never [`panic!`]s.
+    #[must_use]
+    pub fn consume_or_else(self, f: impl FnOnce() -> T) -> T {
+        match self.0 {
+            DropGuardEmptyState::Armed(guard) => guard.consume(),
+            DropGuardEmptyState::Disarmed(value) => value,
+            DropGuardEmptyState::Vacant => f(),
+        }
     }
 
     /// Consumes the guard, returning the inner [`NoDropEmpty`] if armed, or [`None`] if disarmed.
+    ///
+    /// If disarmed, the inner value is dropped. The armed inner guard is moved out, never dropped.
     #[must_use]
-    pub fn into_guard(self) -> Option<NoDropEmpty> {
-        self.0
+    #[doc(alias = "into_no_drop")]
+    pub fn into_guard(self) -> Option<NoDropEmpty<T>> {
+        match self.0 {
+            DropGuardEmptyState::Armed(guard) => Some(guard),
+            DropGuardEmptyState::Disarmed(_) | DropGuardEmptyState::Vacant => None,
+        }
     }
 }
 
-impl From<NoDropEmpty> for DropGuardEmpty {
-    fn from(no_drop: NoDropEmpty) -> Self {
-        Self(Some(no_drop))
+impl<T> From<NoDropEmpty<T>> for DropGuardEmpty<T> {
+    fn from(no_drop: NoDropEmpty<T>) -> Self {
+        Self(DropGuardEmptyState::Armed(no_drop))
     }
 }
 
-impl TryFrom<DropGuardEmpty> for NoDropEmpty {
-    type Error = GuardNotArmed;
+impl<T> TryFrom<DropGuardEmpty<T>> for NoDropEmpty<T> {
+    type Error = GuardError;
 
-    fn try_from(value: DropGuardEmpty) -> Result<Self, Self::Error> {
-        value.into_guard().ok_or(GuardNotArmed)
+    fn try_from(value: DropGuardEmpty<T>) -> Result<Self, Self::Error> {
+        match value.0 {
+            DropGuardEmptyState::Armed(guard) => Ok(guard),
+            DropGuardEmptyState::Disarmed(_) => Err(GuardError::NotArmed),
+            DropGuardEmptyState::Vacant => Err(GuardError::AlreadyConsumed),
+        }
     }
 }
 
@@ -75,17 +254,127 @@ impl TryFrom<DropGuardEmpty> for NoDropEmpty {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::guards::test_macros::{ctor, try_from, transition};
+    use crate::guards::test_macros::{ctor, into_guard, try_from, transition};
 
-    ctor!(new_armed, DropGuardEmpty::new_armed, (), armed, "Value was dropped without being unwrapped");
+    ctor!(new_armed, DropGuardEmpty::new_armed, (), armed, "Value was dropped without being consumed");
     ctor!(new_disarmed, DropGuardEmpty::new_disarmed, (), disarmed);
-    ctor!(from_no_drop, DropGuardEmpty::from, (NoDropEmpty::new()), armed, "Value was dropped without being unwrapped");
+    ctor!(from_no_drop, DropGuardEmpty::from, (NoDropEmpty::new()), armed, "Value was dropped without being consumed");
 
     try_from!(try_from_armed, DropGuardEmpty::new_armed, (), NoDropEmpty, armed);
     try_from!(try_from_disarmed, DropGuardEmpty::new_disarmed, (), NoDropEmpty, disarmed);
 
-    transition!(arm_when_disarmed, DropGuardEmpty::new_disarmed, (), arm, true, armed, "Value was dropped without being unwrapped");
-    transition!(arm_when_armed, DropGuardEmpty::new_armed, (), arm, false, armed, "Value was dropped without being unwrapped");
+    into_guard!(into_guard_armed, DropGuardEmpty::new_armed, (), armed);
+    into_guard!(into_guard_disarmed, DropGuardEmpty::new_disarmed, (), disarmed);
+
+    transition!(arm_when_disarmed, DropGuardEmpty::new_disarmed, (), arm, true, armed, "Value was dropped without being consumed");
+    transition!(arm_when_armed, DropGuardEmpty::new_armed, (), arm, false, armed, "Value was dropped without being consumed");
     transition!(disarm_when_armed, DropGuardEmpty::new_armed, (), disarm, true, disarmed);
     transition!(disarm_when_disarmed, DropGuardEmpty::new_disarmed, (), disarm, false, disarmed);
+
+    mod value {
+        use super::*;
+
+        ctor!(wrap_armed, DropGuardEmpty::wrap_armed, (42), armed, "Value was dropped without being consumed");
+        ctor!(wrap_disarmed, DropGuardEmpty::wrap_disarmed, (42), disarmed);
+        ctor!(from_no_drop, DropGuardEmpty::from, (NoDropEmpty::wrap(42)), armed, "Value was dropped without being consumed");
+
+        try_from!(try_from_armed, DropGuardEmpty::wrap_armed, (42), NoDropEmpty<i32>, armed);
+        try_from!(try_from_disarmed, DropGuardEmpty::wrap_disarmed, (42), NoDropEmpty<i32>, disarmed);
+        try_from!(try_from_taken, DropGuardEmpty::wrap_armed, (42), NoDropEmpty<i32>, taken);
+
+        into_guard!(into_guard_armed, DropGuardEmpty::wrap_armed, (42), armed);
+        into_guard!(into_guard_disarmed, DropGuardEmpty::wrap_disarmed, (42), disarmed);
+
+        transition!(arm_when_disarmed, DropGuardEmpty::wrap_disarmed, (42), arm, true, armed, "Value was dropped without being consumed");
+        transition!(arm_when_armed, DropGuardEmpty::wrap_armed, (42), arm, false, armed, "Value was dropped without being consumed");
+        transition!(disarm_when_armed, DropGuardEmpty::wrap_armed, (42), disarm, true, disarmed);
+        transition!(disarm_when_disarmed, DropGuardEmpty::wrap_disarmed, (42), disarm, false, disarmed);
+
+        transition!(arm_if_true_when_disarmed, DropGuardEmpty::wrap_disarmed, (42), arm_if(|&n| n == 42), true, armed, "Value was dropped without being consumed");
+        transition!(arm_if_false_when_disarmed, DropGuardEmpty::wrap_disarmed, (42), arm_if(|&n| n != 42), false, disarmed);
+        transition!(arm_if_true_when_armed, DropGuardEmpty::wrap_armed, (42), arm_if(|&n| n == 42), false, armed, "Value was dropped without being consumed");
+        transition!(arm_if_false_when_armed, DropGuardEmpty::wrap_armed, (42), arm_if(|&n| n != 42), false, armed, "Value was dropped without being consumed");
+        transition!(disarm_if_true_when_armed, DropGuardEmpty::wrap_armed, (42), disarm_if(|&n| n == 42), true, disarmed);
+        transition!(disarm_if_false_when_armed, DropGuardEmpty::wrap_armed, (42), disarm_if(|&n| n != 42), false, armed, "Value was dropped without being consumed");
+        transition!(disarm_if_true_when_disarmed, DropGuardEmpty::wrap_disarmed, (42), disarm_if(|&n| n == 42), false, disarmed);
+        transition!(disarm_if_false_when_disarmed, DropGuardEmpty::wrap_disarmed, (42), disarm_if(|&n| n != 42), false, disarmed);
+
+        #[test]
+        fn predicate_not_called_when_state_cannot_change() {
+            let mut guard = DropGuardEmpty::wrap_armed(42);
+            assert!(!guard.arm_if(|_| unreachable!()));
+            assert!(guard.disarm());
+            assert!(!guard.disarm_if(|_| unreachable!()));
+        }
+
+        #[test]
+        fn value_survives_arm_and_disarm() {
+            let mut guard = DropGuardEmpty::wrap_disarmed(vec![1, 2, 3]);
+            assert!(guard.arm());
+            assert!(guard.disarm());
+            assert_eq!(guard.consume(), vec![1, 2, 3]);
+        }
+
+        #[test]
+        fn consume_armed() {
+            let guard = DropGuardEmpty::wrap_armed(42);
+            assert_eq!(guard.consume(), 42);
+        }
+
+        #[test]
+        fn consume_or_returns_value_in_both_states() {
+            assert_eq!(DropGuardEmpty::wrap_armed(42).consume_or(0), 42);
+            assert_eq!(DropGuardEmpty::wrap_disarmed(42).consume_or(0), 42);
+            assert_eq!(DropGuardEmpty::wrap_armed(42).consume_or_else(|| unreachable!()), 42);
+            assert_eq!(DropGuardEmpty::wrap_disarmed(42).consume_or_else(|| unreachable!()), 42);
+        }
+
+        #[test]
+        fn try_from_vacant_is_already_consumed() {
+            let guard = DropGuardEmpty::<i32>(DropGuardEmptyState::Vacant);
+            assert_eq!(NoDropEmpty::<i32>::try_from(guard).unwrap_err(), GuardError::AlreadyConsumed);
+        }
+
+        #[test]
+        fn consume_or_returns_default_when_taken() {
+            let mut guard = DropGuardEmpty::wrap_armed(42);
+            assert_eq!(guard.take(), Some(42));
+            assert_eq!(guard.consume_or(0), 0);
+            let guard = DropGuardEmpty(DropGuardEmptyState::Vacant);
+            assert_eq!(guard.consume_or_else(|| 1), 1);
+        }
+
+        #[test]
+        fn take_when_armed() {
+            let mut guard = DropGuardEmpty::wrap_armed(42);
+            assert_eq!(guard.take(), Some(42));
+            assert!(guard.disarmed());
+            assert!(!guard.arm());
+            assert_eq!(guard.take(), None);
+            drop(guard);
+        }
+
+        #[test]
+        fn take_when_disarmed() {
+            let mut guard = DropGuardEmpty::wrap_disarmed(42);
+            assert_eq!(guard.take(), None);
+            assert_eq!(guard.consume(), 42);
+        }
+
+        #[test]
+        #[should_panic(expected = "guard value was already consumed")]
+        fn consume_after_take_panics() {
+            let mut guard = DropGuardEmpty::wrap_armed(42);
+            let _ = guard.take();
+            let _ = guard.consume();
+        }
+
+        #[test]
+        fn clone_keeps_state() {
+            let guard = DropGuardEmpty::wrap_armed(42);
+            let clone = guard.clone();
+            assert!(clone.armed());
+            assert_eq!(guard.consume(), clone.consume());
+        }
+    }
 }
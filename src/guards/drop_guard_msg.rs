@@ -1,6 +1,10 @@
-use std::borrow::Cow;
+use alloc::borrow::Cow;
+use core::fmt;
 
-use crate::{dbg::NoDropMsg, guards::GuardNotArmed};
+use crate::{
+    guards::{ArmScope, GuardError},
+    no_drop::{Message, NoDropMsg, DEFAULT_DROP_PANIC_MSG},
+};
 
 /// A mutable drop guard with custom panic message.
 ///
@@ -10,34 +14,79 @@ use crate::{dbg::NoDropMsg, guards::GuardNotArmed};
 ///
 /// This can be used to guard a critical state or another type, ensuring it is not dropped while in
 /// that state.
+///
+/// The guard is always [`Send`], [`Sync`], and [`Unpin`], and is covariant in `'msg`.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DropGuardMsg<'msg>(DropGuardMsgState<'msg>);
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum DropGuardMsgState<'msg> {
     Armed(NoDropMsg<'msg>),
-    Disarmed(Cow<'msg, str>),
+    Disarmed(Message<'msg>),
 }
 
 impl Default for DropGuardMsgState<'_> {
     fn default() -> Self {
-        Self::Disarmed(Cow::Borrowed(""))
+        Self::Disarmed(Message::default())
     }
 }
 
 impl<'msg> DropGuardMsg<'msg> {
     /// Creates a new armed guard with a custom panic message.
     #[must_use]
+    #[track_caller]
     pub fn new_armed<M: Into<Cow<'msg, str>>>(msg: M) -> Self {
         Self(DropGuardMsgState::Armed(NoDropMsg::guard(msg)))
     }
 
+    /// Creates a new armed guard with a panic message formatted from `args`.
+    ///
+    /// The message is formatted as by [`NoDropMsg::guard_fmt`].
+    #[must_use]
+    #[track_caller]
+    pub fn new_armed_fmt(args: fmt::Arguments<'_>) -> Self {
+        Self(DropGuardMsgState::Armed(NoDropMsg::guard_fmt(args)))
+    }
+
     /// Creates a new disarmed guard with a custom panic message.
     ///
     /// The message is retained and will be used if the guard is later armed.
     #[must_use]
     pub fn new_disarmed<M: Into<Cow<'msg, str>>>(msg: M) -> Self {
-        Self(DropGuardMsgState::Disarmed(msg.into()))
+        Self(DropGuardMsgState::Disarmed(Message::from(msg.into())))
+    }
+
+    /// Creates a guard with a custom panic message, armed if `result` is an [`Err`], and disarmed
+    /// if it is [`Ok`].
+    ///
+    /// This suits a transaction that must be explicitly rolled back if it failed, but may be
+    /// dropped freely once it succeeds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::DropGuard;
+    ///
+    /// let result: Result<i32, &str> = Ok(42);
+    /// let guard = DropGuard::from_result(&result, "failed transaction not rolled back");
+    /// assert!(guard.disarmed());
+    /// ```
+    #[must_use]
+    #[track_caller]
+    pub fn from_result<T, E, M: Into<Cow<'msg, str>>>(result: &Result<T, E>, msg: M) -> Self {
+        match result {
+            Ok(_) => Self::new_disarmed(msg),
+            Err(_) => Self::new_armed(msg),
+        }
+    }
+
+    /// Arms the guard if `result` is an [`Err`], and disarms it if it is [`Ok`].
+    #[track_caller]
+    pub fn set_from_result<T, E>(&mut self, result: &Result<T, E>) {
+        match result {
+            Ok(_) => self.disarm(),
+            Err(_) => self.arm(),
+        };
     }
 
     /// Returns whether the guard is armed.
@@ -55,26 +104,49 @@ impl<'msg> DropGuardMsg<'msg> {
     /// Arms the guard.
     ///
     /// Returns `true` if the guard was armed, or `false` if it was already armed.
+    #[track_caller]
     pub fn arm(&mut self) -> bool {
         match &mut self.0 {
             DropGuardMsgState::Armed(_) => false,
             DropGuardMsgState::Disarmed(msg) => {
-                let msg = std::mem::take(msg);
-                self.0 = DropGuardMsgState::Armed(NoDropMsg::guard(msg));
+                let msg = core::mem::take(msg);
+                self.0 = DropGuardMsgState::Armed(NoDropMsg::from_message(msg));
                 true
             }
         }
     }
 
+    /// Arms the guard until the end of a scope, disarming it when the returned [`ArmScope`] is
+    /// dropped, even by an early return or a [`panic!`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::DropGuard;
+    ///
+    /// let mut guard = DropGuard::new_disarmed("write not finished");
+    /// {
+    ///     let scope = guard.arm_scope();
+    ///     assert!(scope.armed());
+    /// }
+    /// assert!(guard.disarmed());
+    /// ```
+    #[track_caller]
+    pub fn arm_scope(&mut self) -> ArmScope<'_, Self> {
+        ArmScope::new(self)
+    }
+
     /// Disarms the guard.
     ///
     /// Returns `true` if the guard was disarmed or `false` if it was already disarmed.
     pub fn disarm(&mut self) -> bool {
-        match std::mem::take(&mut self.0) {
-            DropGuardMsgState::Disarmed(_) => false,
-            DropGuardMsgState::Armed(guard) => {
-                let msg = guard.unwrap_msg();
+        match core::mem::take(&mut self.0) {
+            DropGuardMsgState::Disarmed(msg) => {
                 self.0 = DropGuardMsgState::Disarmed(msg);
+                false
+            }
+            DropGuardMsgState::Armed(guard) => {
+                self.0 = DropGuardMsgState::Disarmed(guard.into_message());
                 true
             }
         }
@@ -90,6 +162,15 @@ impl<'msg> DropGuardMsg<'msg> {
     }
 }
 
+/// Creates a disarmed guard with [`DEFAULT_DROP_PANIC_MSG`] as its message, used if it is armed.
+///
+/// Unlike a default [`NoDropMsg`], which is armed, this may be dropped freely until armed.
+impl Default for DropGuardMsg<'_> {
+    fn default() -> Self {
+        Self::new_disarmed(DEFAULT_DROP_PANIC_MSG)
+    }
+}
+
 impl<'msg> From<NoDropMsg<'msg>> for DropGuardMsg<'msg> {
     fn from(no_drop: NoDropMsg<'msg>) -> Self {
         Self(DropGuardMsgState::Armed(no_drop))
@@ -97,10 +178,10 @@ impl<'msg> From<NoDropMsg<'msg>> for DropGuardMsg<'msg> {
 }
 
 impl<'msg> TryFrom<DropGuardMsg<'msg>> for NoDropMsg<'msg> {
-    type Error = GuardNotArmed;
+    type Error = GuardError;
 
     fn try_from(value: DropGuardMsg<'msg>) -> Result<Self, Self::Error> {
-        value.into_guard().ok_or(GuardNotArmed)
+        value.into_guard().ok_or(GuardError::NotArmed)
     }
 }
 
@@ -121,9 +202,80 @@ mod tests {
     transition!(disarm_when_armed, DropGuardMsg::new_armed, ("test"), disarm, true, disarmed);
     transition!(disarm_when_disarmed, DropGuardMsg::new_disarmed, ("test"), disarm, false, disarmed);
 
+    #[test]
+    #[should_panic(expected = "guard 7 not released")]
+    fn new_armed_fmt_formats_args() {
+        let id = 7;
+        drop(DropGuardMsg::new_armed_fmt(format_args!("guard {id} not released")));
+    }
+
+    #[test]
+    fn from_result_ok_is_disarmed() {
+        let result: Result<i32, &str> = Ok(42);
+        let guard = DropGuardMsg::from_result(&result, "rolled back");
+        assert!(guard.disarmed());
+        drop(guard);
+    }
+
+    #[test]
+    #[should_panic(expected = "not rolled back")]
+    fn from_result_err_is_armed() {
+        let result: Result<i32, &str> = Err("failed");
+        let guard = DropGuardMsg::from_result(&result, "not rolled back");
+        assert!(guard.armed());
+        drop(guard);
+    }
+
+    #[test]
+    fn set_from_result_follows_result() {
+        let mut guard = DropGuardMsg::new_disarmed("not rolled back");
+        guard.set_from_result::<i32, _>(&Err("failed"));
+        assert!(guard.armed());
+        guard.set_from_result::<_, &str>(&Ok(42));
+        assert!(guard.disarmed());
+    }
+
+    #[test]
+    fn guard_default_is_disarmed() {
+        let guard = DropGuardMsg::default();
+        assert!(guard.disarmed());
+        drop(guard);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn guard_default_armed_uses_default_msg() {
+        let mut guard = DropGuardMsg::default();
+        guard.arm();
+        drop(guard);
+    }
+
     #[test]
     fn default_is_disarmed() {
         let state = DropGuardMsgState::default();
-        assert_eq!(state, DropGuardMsgState::Disarmed(Cow::Borrowed("")));
+        assert_eq!(state, DropGuardMsgState::Disarmed(Message::Borrowed("")));
+    }
+
+    #[test]
+    fn static_msg_round_trips_borrowed() {
+        const MSG: &str = "static message";
+        let mut guard = DropGuardMsg::new_disarmed(MSG);
+        assert!(guard.arm());
+        assert!(guard.disarm());
+        assert!(guard.arm());
+        assert!(guard.disarm());
+
+        let DropGuardMsgState::Disarmed(Message::Borrowed(msg)) = guard.0 else {
+            panic!("message is not borrowed");
+        };
+        assert!(core::ptr::eq(msg, MSG));
+    }
+
+    #[test]
+    #[should_panic(expected = "retained")]
+    fn disarm_when_disarmed_keeps_msg() {
+        let mut guard = DropGuardMsg::new_disarmed("retained");
+        assert!(!guard.disarm());
+        guard.arm();
     }
 }
@@ -1,10 +1,15 @@
-use std::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+use core::marker::PhantomData;
+use core::mem;
 
 use crate::{
-    guards::GuardNotArmed,
-    markers::{Empty, Msg, PassMarker},
-    no_drop::{NoDropPassEmpty, NoDropPassMsg},
+    guards::GuardError,
+    markers::{Empty, PassMarker},
+    no_drop::NoDropPassEmpty,
 };
+#[cfg(feature = "alloc")]
+use crate::{markers::Msg, no_drop::NoDropPassMsg};
 
 /// A zero-cost wrapper with no drop checking.
 ///
@@ -14,59 +19,185 @@ use crate::{
 ///
 /// The type parameter `M` is a zero-sized marker that distinguishes between
 /// the plain variant ([`Empty`]) and the message variant ([`Msg`]).
+/// It is only held as `fn() -> M`, so it never affects the auto traits or variance of the
+/// guard, which match [`DropGuard`](super::DropGuard) and [`DropGuardMsg`](super::DropGuardMsg).
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[doc(hidden)]
-#[must_use]
-pub struct DropGuardPass<'msg, M: PassMarker = Empty> {
-    armed: bool,
-    _lifetime: std::marker::PhantomData<&'msg ()>,
-    _marker: std::marker::PhantomData<M>,
+#[must_use = "an armed guard panics when dropped, so it must be kept alive and disarmed"]
+pub struct DropGuardPass<'msg, M: PassMarker = Empty, T = ()> {
+    state: DropGuardPassState<T>,
+    /// Covariant in `'msg`, like the message held by [`DropGuard`](super::DropGuard).
+    _lifetime: PhantomData<&'msg ()>,
+    _marker: PhantomData<fn() -> M>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DropGuardPassState<T> {
+    Armed(T),
+    Disarmed(T),
+    /// The value was taken by [`DropGuardPass::take`], or is being moved between
+    /// [`Self::Armed`] and [`Self::Disarmed`].
+    Vacant,
 }
 
 // Implementation for DropGuardPass<Empty> (no message variant)
-#[allow(dead_code)]
 impl DropGuardPass<'static, Empty> {
     /// Creates a new armed guard.
     pub fn new_armed() -> Self {
-        Self { armed: true, _lifetime: std::marker::PhantomData, _marker: std::marker::PhantomData }
+        Self::wrap_armed(())
     }
 
     /// Creates a new disarmed guard.
     pub fn new_disarmed() -> Self {
-        Self { armed: false, _lifetime: std::marker::PhantomData, _marker: std::marker::PhantomData }
+        Self::wrap_disarmed(())
+    }
+
+    /// Creates a [`PanicGuard`](super::PanicGuard) that runs `cleanup` only if it is dropped while
+    /// the thread is [`panic!`]ing.
+    ///
+    /// The cleanup is not a drop check, so it runs in every build.
+    #[cfg(feature = "std")]
+    pub const fn panic_only<F: FnOnce()>(cleanup: F) -> super::PanicGuard<F> {
+        super::PanicGuard::new(cleanup)
+    }
+}
+
+impl<T> DropGuardPass<'static, Empty, T> {
+    /// Creates a new armed guard around `value`.
+    pub fn wrap_armed(value: T) -> Self {
+        Self::from_state(DropGuardPassState::Armed(value))
+    }
+
+    /// Creates a new disarmed guard around `value`.
+    pub fn wrap_disarmed(value: T) -> Self {
+        Self::from_state(DropGuardPassState::Disarmed(value))
+    }
+
+    /// Arms the guard if it is disarmed and `pred` returns `true` for the inner value.
+    ///
+    /// Returns `true` if the guard was armed, or `false` if it was already armed or `pred`
+    /// returned `false`.
+    pub fn arm_if(&mut self, pred: impl FnOnce(&T) -> bool) -> bool {
+        match &self.state {
+            DropGuardPassState::Disarmed(value) if pred(value) => self.arm(),
+            _ => false,
+        }
+    }
+
+    /// Disarms the guard if it is armed and `pred` returns `true` for the inner value.
+    ///
+    /// Returns `true` if the guard was disarmed, or `false` if it was already disarmed or `pred`
+    /// returned `false`.
+    pub fn disarm_if(&mut self, pred: impl FnOnce(&T) -> bool) -> bool {
+        match &self.state {
+            DropGuardPassState::Armed(value) if pred(value) => self.disarm(),
+            _ => false,
+        }
+    }
+
+    /// Takes the value out of an armed guard, leaving it disarmed without a value.
+    ///
+    /// Returns [`None`], leaving the guard unchanged, if it is disarmed.
+    pub fn take(&mut self) -> Option<T> {
+        match mem::replace(&mut self.state, DropGuardPassState::Vacant) {
+            DropGuardPassState::Armed(value) => Some(value),
+            state => {
+                self.state = state;
+                None
+            }
+        }
+    }
+
+    /// Consumes the guard, returning the inner value whether armed or disarmed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value was taken by [`Self::take`].
+    #[must_use]
+    pub fn consume(self) -> T {
+        self.consume_or_else(|| panic!("{}", GuardError::AlreadyConsumed))
+    }
+
+    /// Consumes the guard, returning the inner value whether armed or disarmed, or `default` if
+    /// the value was taken.
+    #[must_use]
+    pub fn consume_or(self, default: T) -> T {
+        self.consume_or_else(|| default)
+    }
+
+    /// Consumes the guard, returning the inner value whether armed or disarmed, or the result of
+    /// `f` if the value was taken.
+    #[must_use]
+    pub fn consume_or_else(self, f: impl FnOnce() -> T) -> T {
+        match self.state {
+            DropGuardPassState::Armed(value) | DropGuardPassState::Disarmed(value) => value,
+            DropGuardPassState::Vacant => f(),
+        }
     }
 
     /// Consumes the guard, returning the inner [`NoDropPassEmpty`] if armed, or [`None`] if disarmed.
     #[must_use]
-    pub fn into_guard(self) -> Option<NoDropPassEmpty> {
-        match self.armed {
-            true => Some(NoDropPassEmpty::new()),
-            false => None,
+    #[doc(alias = "into_no_drop")]
+    pub fn into_guard(self) -> Option<NoDropPassEmpty<T>> {
+        match self.state {
+            DropGuardPassState::Armed(value) => Some(NoDropPassEmpty::wrap(value)),
+            _ => None,
         }
     }
 }
 
 // Implementation for DropGuardPass<Msg> (message variant)
-#[allow(dead_code)]
+#[cfg(feature = "alloc")]
 impl<'msg> DropGuardPass<'msg, Msg> {
     /// Creates a new armed guard with a custom panic message.
     ///
     /// The message is immediately dropped and ignored, since this type never [`panic!`]s.
     pub fn new_armed<M: Into<Cow<'msg, str>>>(_msg: M) -> Self {
-        Self { armed: true, _lifetime: std::marker::PhantomData, _marker: std::marker::PhantomData }
+        Self::from_state(DropGuardPassState::Armed(()))
+    }
+
+    /// Creates a new armed guard with a formatted panic message.
+    ///
+    /// The arguments are ignored, since this type never [`panic!`]s.
+    pub fn new_armed_fmt(_args: core::fmt::Arguments<'_>) -> Self {
+        Self::from_state(DropGuardPassState::Armed(()))
     }
 
     /// Creates a new disarmed guard with a custom panic message.
     ///
     /// The message is immediately dropped and ignored, since this type never [`panic!`]s.
     pub fn new_disarmed<M: Into<Cow<'msg, str>>>(_msg: M) -> Self {
-        Self { armed: false, _lifetime: std::marker::PhantomData, _marker: std::marker::PhantomData }
+        Self::from_state(DropGuardPassState::Disarmed(()))
+    }
+
+    /// Creates a guard with a custom panic message, armed if `result` is an [`Err`], and disarmed
+    /// if it is [`Ok`].
+    ///
+    /// The message is immediately dropped and ignored, since this type never [`panic!`]s.
+    pub fn from_result<T, E, M: Into<Cow<'msg, str>>>(result: &Result<T, E>, _msg: M) -> Self {
+        match result {
+            Ok(_) => Self::from_state(DropGuardPassState::Disarmed(())),
+            Err(_) => Self::from_state(DropGuardPassState::Armed(())),
+        }
+    }
+
+    /// Arms the guard if `result` is an [`Err`], and disarms it if it is [`Ok`].
+    pub fn set_from_result<T, E>(&mut self, result: &Result<T, E>) {
+        match result {
+            Ok(_) => self.disarm(),
+            Err(_) => self.arm(),
+        };
+    }
+
+    /// Arms the guard until the returned [`ArmScope`](super::ArmScope) is dropped.
+    pub fn arm_scope(&mut self) -> super::ArmScope<'_, Self> {
+        super::ArmScope::new(self)
     }
 
     /// Consumes the guard, returning the inner [`NoDropPassMsg`] if armed, or [`None`] if disarmed.
     #[must_use]
     pub fn into_guard(self) -> Option<NoDropPassMsg<'msg>> {
-        match self.armed {
+        match self.armed() {
             true => Some(NoDropPassMsg::guard("")),
             false => None,
         }
@@ -74,65 +205,100 @@ impl<'msg> DropGuardPass<'msg, Msg> {
 }
 
 // Shared implementation for both variants
-#[allow(dead_code)]
-impl<M: PassMarker> DropGuardPass<'_, M> {
+impl<M: PassMarker, T> DropGuardPass<'_, M, T> {
+    fn from_state(state: DropGuardPassState<T>) -> Self {
+        Self { state, _lifetime: PhantomData, _marker: PhantomData }
+    }
+
     /// Returns whether the guard is armed.
     pub fn armed(&self) -> bool {
-        self.armed
+        matches!(self.state, DropGuardPassState::Armed(_))
     }
 
     /// Returns whether the guard is disarmed.
     pub fn disarmed(&self) -> bool {
-        !self.armed
+        !self.armed()
     }
 
     /// Arms the guard.
     ///
-    /// Returns `true` if the guard was armed, or `false` if it was already armed.
+    /// Returns `true` if the guard was armed, or `false` if it was already armed, or its value was
+    /// taken.
     pub fn arm(&mut self) -> bool {
-        !std::mem::replace(&mut self.armed, true)
+        match mem::replace(&mut self.state, DropGuardPassState::Vacant) {
+            DropGuardPassState::Disarmed(value) => {
+                self.state = DropGuardPassState::Armed(value);
+                true
+            }
+            state => {
+                self.state = state;
+                false
+            }
+        }
     }
 
     /// Disarms the guard.
     ///
     /// Returns `true` if the guard was disarmed or `false` if it was already disarmed.
     pub fn disarm(&mut self) -> bool {
-        std::mem::replace(&mut self.armed, false)
+        match mem::replace(&mut self.state, DropGuardPassState::Vacant) {
+            DropGuardPassState::Armed(value) => {
+                self.state = DropGuardPassState::Disarmed(value);
+                true
+            }
+            state => {
+                self.state = state;
+                false
+            }
+        }
+    }
+}
+
+impl<T> From<NoDropPassEmpty<T>> for DropGuardPass<'static, Empty, T> {
+    fn from(no_drop: NoDropPassEmpty<T>) -> Self {
+        Self::wrap_armed(no_drop.consume())
     }
 }
 
-impl From<NoDropPassEmpty> for DropGuardPass<'_, Empty> {
-    fn from(_: NoDropPassEmpty) -> Self {
-        Self { armed: true, _lifetime: std::marker::PhantomData, _marker: std::marker::PhantomData }
+#[cfg(feature = "alloc")]
+impl Default for DropGuardPass<'_, Msg> {
+    fn default() -> Self {
+        Self::from_state(DropGuardPassState::Disarmed(()))
     }
 }
 
+#[cfg(feature = "alloc")]
 impl From<NoDropPassMsg<'_>> for DropGuardPass<'_, Msg> {
     fn from(_: NoDropPassMsg<'_>) -> Self {
-        Self { armed: true, _lifetime: std::marker::PhantomData, _marker: std::marker::PhantomData }
+        Self::from_state(DropGuardPassState::Armed(()))
     }
 }
 
-impl TryFrom<DropGuardPass<'static, Empty>> for NoDropPassEmpty {
-    type Error = GuardNotArmed;
+impl<T> TryFrom<DropGuardPass<'static, Empty, T>> for NoDropPassEmpty<T> {
+    type Error = GuardError;
 
-    fn try_from(value: DropGuardPass<'static, Empty>) -> Result<Self, Self::Error> {
-        value.into_guard().ok_or(GuardNotArmed)
+    fn try_from(value: DropGuardPass<'static, Empty, T>) -> Result<Self, Self::Error> {
+        match value.state {
+            DropGuardPassState::Armed(value) => Ok(NoDropPassEmpty::wrap(value)),
+            DropGuardPassState::Disarmed(_) => Err(GuardError::NotArmed),
+            DropGuardPassState::Vacant => Err(GuardError::AlreadyConsumed),
+        }
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'msg> TryFrom<DropGuardPass<'msg, Msg>> for NoDropPassMsg<'msg> {
-    type Error = GuardNotArmed;
+    type Error = GuardError;
 
     fn try_from(value: DropGuardPass<'msg, Msg>) -> Result<Self, Self::Error> {
-        value.into_guard().ok_or(GuardNotArmed)
+        value.into_guard().ok_or(GuardError::NotArmed)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::guards::test_macros::{ctor, transition, try_from};
+    use crate::guards::test_macros::{ctor, into_guard, transition, try_from};
 
     mod empty {
         use super::*;
@@ -143,6 +309,10 @@ mod tests {
 
         try_from!(try_from_armed, DropGuardPass::<Empty>::new_armed, (), NoDropPassEmpty, armed);
         try_from!(try_from_disarmed, DropGuardPass::<Empty>::new_disarmed, (), NoDropPassEmpty, disarmed);
+        try_from!(try_from_taken, DropGuardPass::<Empty, _>::wrap_armed, (42), NoDropPassEmpty<i32>, taken);
+
+        into_guard!(into_guard_armed, DropGuardPass::<Empty>::new_armed, (), armed);
+        into_guard!(into_guard_disarmed, DropGuardPass::<Empty>::new_disarmed, (), disarmed);
 
         transition!(arm_when_disarmed, DropGuardPass::<Empty>::new_disarmed, (), arm, true, armed_no_panic);
         transition!(arm_when_armed, DropGuardPass::<Empty>::new_armed, (), arm, false, armed_no_panic);
@@ -150,10 +320,84 @@ mod tests {
         transition!(disarm_when_disarmed, DropGuardPass::<Empty>::new_disarmed, (), disarm, false, disarmed);
     }
 
+    mod value {
+        use super::*;
+
+        ctor!(wrap_armed, DropGuardPass::<Empty, _>::wrap_armed, (42), armed_no_panic);
+        ctor!(wrap_disarmed, DropGuardPass::<Empty, _>::wrap_disarmed, (42), disarmed);
+        ctor!(from, DropGuardPass::<Empty, _>::from, (NoDropPassEmpty::wrap(42)), armed_no_panic);
+
+        try_from!(try_from_armed, DropGuardPass::<Empty, _>::wrap_armed, (42), NoDropPassEmpty<i32>, armed);
+        try_from!(try_from_disarmed, DropGuardPass::<Empty, _>::wrap_disarmed, (42), NoDropPassEmpty<i32>, disarmed);
+
+        transition!(arm_when_disarmed, DropGuardPass::<Empty, _>::wrap_disarmed, (42), arm, true, armed_no_panic);
+        transition!(disarm_when_armed, DropGuardPass::<Empty, _>::wrap_armed, (42), disarm, true, disarmed);
+
+        #[test]
+        fn consume_returns_value() {
+            let guard = DropGuardPass::<Empty, _>::wrap_armed(42);
+            assert_eq!(guard.consume(), 42);
+        }
+
+        transition!(
+            arm_if_true_when_disarmed,
+            DropGuardPass::<Empty, _>::wrap_disarmed,
+            (42),
+            arm_if(|&n| n == 42),
+            true,
+            armed_no_panic
+        );
+        transition!(
+            arm_if_false_when_disarmed,
+            DropGuardPass::<Empty, _>::wrap_disarmed,
+            (42),
+            arm_if(|&n| n != 42),
+            false,
+            disarmed
+        );
+        transition!(
+            disarm_if_true_when_armed,
+            DropGuardPass::<Empty, _>::wrap_armed,
+            (42),
+            disarm_if(|&n| n == 42),
+            true,
+            disarmed
+        );
+        transition!(
+            disarm_if_false_when_armed,
+            DropGuardPass::<Empty, _>::wrap_armed,
+            (42),
+            disarm_if(|&n| n != 42),
+            false,
+            armed_no_panic
+        );
+
+        #[test]
+        fn take_matches_checked_guard() {
+            let mut guard = DropGuardPass::<Empty, _>::wrap_armed(42);
+            assert_eq!(guard.take(), Some(42));
+            assert!(guard.disarmed());
+            assert!(!guard.arm());
+            assert_eq!(guard.consume_or(0), 0);
+
+            let mut guard = DropGuardPass::<Empty, _>::wrap_disarmed(42);
+            assert_eq!(guard.take(), None);
+            assert_eq!(guard.consume(), 42);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
     mod msg {
         use super::*;
 
         ctor!(new_armed, DropGuardPass::<Msg>::new_armed, ("message"), armed_no_panic);
+        ctor!(from_result_ok, DropGuardPass::<Msg>::from_result, (&Ok::<i32, &str>(42), "message"), disarmed);
+        ctor!(
+            from_result_err,
+            DropGuardPass::<Msg>::from_result,
+            (&Err::<i32, _>("failed"), "message"),
+            armed_no_panic
+        );
         ctor!(new_disarmed, DropGuardPass::<Msg>::new_disarmed, ("message"), disarmed);
         ctor!(from, DropGuardPass::<Msg>::from, (NoDropPassMsg::guard("message")), armed_no_panic);
 
@@ -165,4 +409,17 @@ mod tests {
         transition!(disarm_when_armed, DropGuardPass::<Msg>::new_armed, ("test"), disarm, true, disarmed);
         transition!(disarm_when_disarmed, DropGuardPass::<Msg>::new_disarmed, ("test"), disarm, false, disarmed);
     }
+
+    // Both are covariant in `'msg`, so the `dbg` alias accepts the same lifetimes in every build.
+    #[cfg(feature = "alloc")]
+    #[allow(dead_code)]
+    fn passthrough_is_covariant<'a>(guard: DropGuardPass<'static, Msg>) -> DropGuardPass<'a, Msg> {
+        guard
+    }
+
+    #[cfg(feature = "alloc")]
+    #[allow(dead_code)]
+    fn guard_is_covariant<'a>(guard: crate::guards::DropGuard<'static>) -> crate::guards::DropGuard<'a> {
+        guard
+    }
 }
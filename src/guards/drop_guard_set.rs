@@ -0,0 +1,150 @@
+use alloc::format;
+use alloc::vec::Vec;
+
+use crate::guards::{DropGuardEmpty, GuardSliceExt};
+use crate::no_drop::{panic_with, Origin};
+
+/// A collection of [`DropGuardEmpty`]s that can be armed and disarmed as one unit.
+///
+/// If the set is dropped while any member is armed, it [`panic!`]s once, reporting how many of its
+/// guards were still armed. Members are disarmed before the report, so they do not [`panic!`]
+/// individually.
+///
+/// # Examples
+///
+/// ```rust
+/// use no_drop::rls::{DropGuardEmpty, DropGuardSet};
+///
+/// let mut set = DropGuardSet::new();
+/// set.push(DropGuardEmpty::new_disarmed());
+/// set.push(DropGuardEmpty::new_disarmed());
+///
+/// set.arm_all();
+/// assert!(set.all_armed());
+///
+/// set.disarm_all();
+/// ```
+#[derive(Debug)]
+pub struct DropGuardSet {
+    guards: Vec<DropGuardEmpty>,
+    origin: Origin,
+}
+
+impl DropGuardSet {
+    /// Creates a new, empty set.
+    #[must_use]
+    #[track_caller]
+    pub fn new() -> Self {
+        Self { guards: Vec::new(), origin: Origin::caller() }
+    }
+
+    /// Adds `guard` to the set, in whatever state it is in.
+    pub fn push(&mut self, guard: DropGuardEmpty) {
+        self.guards.push(guard);
+    }
+
+    /// Arms every guard in the set.
+    ///
+    /// Returns the number of guards that were disarmed, and are now armed.
+    #[track_caller]
+    pub fn arm_all(&mut self) -> usize {
+        self.guards.arm_all()
+    }
+
+    /// Disarms every guard in the set.
+    ///
+    /// Returns the number of guards that were armed, and are now disarmed.
+    pub fn disarm_all(&mut self) -> usize {
+        self.guards.disarm_all()
+    }
+
+    /// Returns whether every guard in the set is armed. An empty set is considered armed.
+    #[must_use]
+    pub fn all_armed(&self) -> bool {
+        self.guards.iter().all(DropGuardEmpty::armed)
+    }
+
+    /// Returns the number of guards in the set that are armed.
+    #[must_use]
+    pub fn armed_count(&self) -> usize {
+        self.guards.count_armed()
+    }
+
+    /// Returns the number of guards in the set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.guards.len()
+    }
+
+    /// Returns whether the set contains no guards.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.guards.is_empty()
+    }
+}
+
+impl Default for DropGuardSet {
+    #[track_caller]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FromIterator<DropGuardEmpty> for DropGuardSet {
+    #[track_caller]
+    fn from_iter<I: IntoIterator<Item = DropGuardEmpty>>(iter: I) -> Self {
+        Self { guards: iter.into_iter().collect(), origin: Origin::caller() }
+    }
+}
+
+impl Drop for DropGuardSet {
+    /// [`panic!`]s if any guard is armed, unless the thread is already panicking.
+    fn drop(&mut self) {
+        let armed = self.disarm_all();
+        if armed > 0 {
+            panic_with(&format!("{armed} of {} guards were still armed", self.guards.len()), self.origin);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arm_all_and_disarm_all() {
+        let mut set: DropGuardSet = [DropGuardEmpty::new_armed(), DropGuardEmpty::new_disarmed()].into_iter().collect();
+        assert!(!set.all_armed());
+
+        assert_eq!(set.arm_all(), 1);
+        assert!(set.all_armed());
+        assert_eq!(set.armed_count(), 2);
+
+        assert_eq!(set.disarm_all(), 2);
+        assert_eq!(set.armed_count(), 0);
+    }
+
+    #[test]
+    fn disarmed_set_does_not_panic() {
+        let mut set = DropGuardSet::new();
+        set.push(DropGuardEmpty::new_disarmed());
+        set.push(DropGuardEmpty::new_disarmed());
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn empty_set_is_all_armed() {
+        let set = DropGuardSet::default();
+        assert!(set.is_empty());
+        assert!(set.all_armed());
+    }
+
+    #[test]
+    #[should_panic(expected = "2 of 3 guards were still armed")]
+    fn mixed_set_reports_armed_count() {
+        let mut set = DropGuardSet::new();
+        set.push(DropGuardEmpty::new_armed());
+        set.push(DropGuardEmpty::new_disarmed());
+        set.push(DropGuardEmpty::new_armed());
+    }
+}
@@ -0,0 +1,146 @@
+use sealed::sealed;
+
+#[cfg(feature = "alloc")]
+use super::DropGuard;
+use super::{drop_guard_pass::DropGuardPass, DropGuardEmpty};
+#[cfg(feature = "std")]
+use super::{DropGuardAbortEmpty, DropGuardAbortMsg};
+use crate::markers::PassMarker;
+
+/// Extension methods for arming and disarming a slice, or [`Vec`](alloc::vec::Vec), of guards
+/// together.
+///
+/// This trait is sealed and cannot be implemented outside this crate.
+///
+/// # Examples
+///
+/// ```rust
+/// use no_drop::rls::{DropGuardEmpty, GuardSliceExt};
+///
+/// let mut guards = vec![DropGuardEmpty::new_armed(), DropGuardEmpty::new_disarmed()];
+/// assert_eq!(guards.count_armed(), 1);
+///
+/// assert_eq!(guards.disarm_all(), 1);
+/// assert!(!guards.any_armed());
+/// ```
+#[sealed]
+pub trait GuardSliceExt {
+    /// Arms every guard, each keeping its own message.
+    ///
+    /// Returns the number of guards that were disarmed, and are now armed.
+    #[track_caller]
+    fn arm_all(&mut self) -> usize;
+
+    /// Disarms every guard, so they may all be dropped.
+    ///
+    /// Returns the number of guards that were armed, and are now disarmed.
+    fn disarm_all(&mut self) -> usize;
+
+    /// Returns whether any guard is armed.
+    #[must_use]
+    fn any_armed(&self) -> bool;
+
+    /// Returns the number of guards that are armed.
+    #[must_use]
+    fn count_armed(&self) -> usize;
+}
+
+macro_rules! impl_guard_slice_ext {
+    ($([$($generics:tt)*] $ty:ty),* $(,)?) => {
+        $(
+            #[sealed]
+            impl<$($generics)*> GuardSliceExt for [$ty] {
+                #[track_caller]
+                fn arm_all(&mut self) -> usize {
+                    let mut armed = 0;
+                    for guard in self {
+                        armed += usize::from(guard.arm());
+                    }
+                    armed
+                }
+
+                fn disarm_all(&mut self) -> usize {
+                    self.iter_mut().map(<$ty>::disarm).filter(|&disarmed| disarmed).count()
+                }
+
+                fn any_armed(&self) -> bool {
+                    self.iter().any(<$ty>::armed)
+                }
+
+                fn count_armed(&self) -> usize {
+                    self.iter().filter(|guard| guard.armed()).count()
+                }
+            }
+        )*
+    };
+}
+
+impl_guard_slice_ext!([T] DropGuardEmpty<T>, ['msg, M: PassMarker, T] DropGuardPass<'msg, M, T>);
+
+#[cfg(feature = "alloc")]
+impl_guard_slice_ext!(['msg] DropGuard<'msg>);
+
+#[cfg(feature = "std")]
+impl_guard_slice_ext!([] DropGuardAbortEmpty, ['msg] DropGuardAbortMsg<'msg>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mixed_empty_guards() {
+        let mut guards =
+            Vec::from([DropGuardEmpty::new_armed(), DropGuardEmpty::new_disarmed(), DropGuardEmpty::new_armed()]);
+        assert!(guards.any_armed());
+        assert_eq!(guards.count_armed(), 2);
+
+        assert_eq!(guards.arm_all(), 1);
+        assert!(guards.iter().all(DropGuardEmpty::armed));
+        assert_eq!(guards.count_armed(), 3);
+
+        assert_eq!(guards.disarm_all(), 3);
+        assert!(!guards.any_armed());
+        assert_eq!(guards.disarm_all(), 0);
+    }
+
+    #[test]
+    fn empty_slice() {
+        let mut guards: [DropGuardEmpty; 0] = [];
+        assert!(!guards.any_armed());
+        assert_eq!(guards.count_armed(), 0);
+        assert_eq!(guards.arm_all(), 0);
+        assert_eq!(guards.disarm_all(), 0);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn mixed_msg_guards() {
+        let mut guards = Vec::from([DropGuard::new_armed("first"), DropGuard::new_disarmed("second")]);
+        assert_eq!(guards.count_armed(), 1);
+
+        assert_eq!(guards.arm_all(), 1);
+        assert_eq!(guards.count_armed(), 2);
+
+        assert_eq!(guards.disarm_all(), 2);
+        assert!(!guards.any_armed());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic(expected = "second")]
+    fn arm_all_keeps_msg() {
+        let mut guards = vec![DropGuard::new_disarmed("second")];
+        guards.arm_all();
+        drop(guards);
+    }
+
+    #[test]
+    fn passthrough_guards() {
+        use crate::guards::DropGuardPassthroughEmpty;
+
+        let mut guards = [DropGuardPassthroughEmpty::new_armed(), DropGuardPassthroughEmpty::new_disarmed()];
+        assert_eq!(guards.arm_all(), 1);
+        assert_eq!(guards.count_armed(), 2);
+        assert_eq!(guards.disarm_all(), 2);
+    }
+}
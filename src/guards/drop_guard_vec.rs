@@ -0,0 +1,119 @@
+use alloc::vec::Vec;
+use sealed::sealed;
+
+use super::{drop_guard_pass::DropGuardPass, DropGuard, DropGuardEmpty, GuardSliceExt};
+#[cfg(feature = "std")]
+use super::{DropGuardAbortEmpty, DropGuardAbortMsg};
+use crate::markers::PassMarker;
+
+/// Extension methods for removing guards from a [`Vec`], disarming them as they are removed.
+///
+/// [`Vec::clear`] and [`Vec::retain`] drop the guards they remove, [`panic!`]ing if any of them
+/// are armed. These methods disarm each guard before it is removed instead.
+///
+/// This trait is sealed and cannot be implemented outside this crate.
+///
+/// # Examples
+///
+/// ```rust
+/// use no_drop::rls::{DropGuardEmpty, GuardVecExt};
+///
+/// let mut guards = vec![DropGuardEmpty::new_armed(), DropGuardEmpty::new_disarmed(), DropGuardEmpty::new_armed()];
+/// assert_eq!(guards.retain_armed(DropGuardEmpty::disarmed), 2);
+/// assert_eq!(guards.len(), 1);
+///
+/// guards.push(DropGuardEmpty::new_armed());
+/// assert_eq!(guards.disarm_and_clear(), 1);
+/// assert!(guards.is_empty());
+/// ```
+#[sealed]
+pub trait GuardVecExt {
+    /// The type of guard held by the [`Vec`].
+    type Guard;
+
+    /// Disarms every guard, then removes them all.
+    ///
+    /// Returns the number of guards that were armed.
+    fn disarm_and_clear(&mut self) -> usize;
+
+    /// Retains only the guards for which `pred` returns `true`, disarming the rest before they are
+    /// removed.
+    ///
+    /// Returns the number of removed guards that were armed.
+    fn retain_armed(&mut self, pred: impl FnMut(&Self::Guard) -> bool) -> usize;
+}
+
+macro_rules! impl_guard_vec_ext {
+    ($([$($generics:tt)*] $ty:ty),* $(,)?) => {
+        $(
+            #[sealed]
+            impl<$($generics)*> GuardVecExt for Vec<$ty> {
+                type Guard = $ty;
+
+                fn disarm_and_clear(&mut self) -> usize {
+                    let disarmed = self.disarm_all();
+                    self.clear();
+                    disarmed
+                }
+
+                fn retain_armed(&mut self, mut pred: impl FnMut(&Self::Guard) -> bool) -> usize {
+                    let mut disarmed = 0;
+                    self.retain_mut(|guard| {
+                        let keep = pred(guard);
+                        if !keep {
+                            disarmed += usize::from(guard.disarm());
+                        }
+                        keep
+                    });
+                    disarmed
+                }
+            }
+        )*
+    };
+}
+
+impl_guard_vec_ext!(
+    [T] DropGuardEmpty<T>,
+    ['msg, M: PassMarker, T] DropGuardPass<'msg, M, T>,
+    ['msg] DropGuard<'msg>,
+);
+
+#[cfg(feature = "std")]
+impl_guard_vec_ext!([] DropGuardAbortEmpty, ['msg] DropGuardAbortMsg<'msg>);
+
+#[cfg(test)]
+mod tests {
+    use core::mem;
+
+    use super::*;
+
+    #[test]
+    fn disarm_and_clear() {
+        let mut guards = Vec::from([DropGuardEmpty::new_armed(), DropGuardEmpty::new_disarmed()]);
+        assert_eq!(guards.disarm_and_clear(), 1);
+        assert!(guards.is_empty());
+    }
+
+    #[test]
+    fn retain_armed_disarms_removed() {
+        let mut guards =
+            Vec::from([DropGuardEmpty::wrap_armed(1), DropGuardEmpty::wrap_disarmed(2), DropGuardEmpty::wrap_armed(3)]);
+        let mut index = 0;
+        let removed = guards.retain_armed(|_| {
+            index += 1;
+            index == 3
+        });
+        assert_eq!(removed, 1);
+        assert_eq!(guards.len(), 1);
+        assert_eq!(guards.pop().unwrap().consume(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "kept")]
+    fn retain_armed_keeps_armed() {
+        let mut guards = Vec::from([DropGuard::new_armed("kept"), DropGuard::new_armed("removed")]);
+        let mut first = true;
+        assert_eq!(guards.retain_armed(|_| mem::take(&mut first)), 1);
+        drop(guards);
+    }
+}
@@ -1,17 +1,113 @@
+#[cfg(feature = "alloc")]
+mod arm_scope;
+#[cfg(feature = "std")]
+mod catch;
+#[cfg(feature = "std")]
+mod drop_guard_abort;
 mod drop_guard_empty;
+#[cfg(feature = "alloc")]
 mod drop_guard_msg;
 mod drop_guard_pass;
+#[cfg(feature = "alloc")]
+mod drop_guard_set;
+mod drop_guard_slice;
+#[cfg(feature = "alloc")]
+mod drop_guard_vec;
+#[cfg(feature = "std")]
+mod panic_guard;
 
+#[cfg(feature = "alloc")]
+pub use arm_scope::ArmScope;
+#[cfg(feature = "std")]
+pub use catch::{catch_unconsumed, GuardLeaked};
+#[cfg(feature = "std")]
+pub use drop_guard_abort::{DropGuardAbortEmpty, DropGuardAbortMsg};
 pub use drop_guard_empty::DropGuardEmpty;
+#[cfg(feature = "alloc")]
 pub use drop_guard_msg::DropGuardMsg as DropGuard;
+#[cfg(feature = "alloc")]
+pub use drop_guard_set::DropGuardSet;
+pub use drop_guard_slice::GuardSliceExt;
+#[cfg(feature = "alloc")]
+pub use drop_guard_vec::GuardVecExt;
+#[cfg(feature = "std")]
+pub use panic_guard::PanicGuard;
 
 #[cfg(test)]
 mod test_macros;
-#[allow(dead_code)]
-pub(crate) type DropGuardPassthroughEmpty = drop_guard_pass::DropGuardPass<'static, crate::markers::Empty>;
-#[allow(dead_code)]
-pub(crate) type DropGuardPassthroughMsg<'msg> = drop_guard_pass::DropGuardPass<'msg, crate::markers::Msg>;
+#[doc(hidden)]
+pub type DropGuardPassthroughEmpty<T = ()> = drop_guard_pass::DropGuardPass<'static, crate::markers::Empty, T>;
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub type DropGuardPassthroughMsg<'msg> = drop_guard_pass::DropGuardPass<'msg, crate::markers::Msg>;
 
+/// The error of a guard that was not armed.
+///
+/// Conversions from a guard report [`GuardError`] instead, which this converts into as
+/// [`GuardError::NotArmed`]. Prefer matching on [`GuardError`].
 #[derive(Debug, thiserror::Error)]
 #[error("guard is not armed")]
 pub struct GuardNotArmed;
+
+impl GuardNotArmed {
+    /// Returns the error message, the same text it displays as.
+    #[must_use]
+    pub const fn msg(&self) -> &'static str {
+        "guard is not armed"
+    }
+}
+
+impl AsRef<str> for GuardNotArmed {
+    fn as_ref(&self) -> &str {
+        self.msg()
+    }
+}
+
+/// The reason a guard could not be converted into its armed [`NoDrop`](crate::rls::NoDrop) form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum GuardError {
+    /// The guard is disarmed, so there is no armed value to convert.
+    #[error("guard is not armed")]
+    NotArmed,
+    /// The guard's value was already consumed, so there is nothing to convert.
+    #[error("guard value was already consumed")]
+    AlreadyConsumed,
+}
+
+impl From<GuardNotArmed> for GuardError {
+    fn from(_: GuardNotArmed) -> Self {
+        Self::NotArmed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guard_error_display() {
+        assert_eq!(GuardError::NotArmed.to_string(), "guard is not armed");
+        assert_eq!(GuardError::AlreadyConsumed.to_string(), "guard value was already consumed");
+        assert_eq!(GuardError::from(GuardNotArmed), GuardError::NotArmed);
+    }
+
+    #[test]
+    fn guard_not_armed_msg() {
+        assert_eq!(GuardNotArmed.msg(), GuardNotArmed.to_string());
+        assert_eq!(GuardNotArmed.as_ref(), "guard is not armed");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn guard_error_propagates_as_boxed_error() {
+        fn convert(guard: DropGuardEmpty) -> Result<(), Box<dyn std::error::Error>> {
+            crate::no_drop::NoDropEmpty::<()>::try_from(guard)?.forget();
+            Ok(())
+        }
+
+        assert!(convert(DropGuardEmpty::new_armed()).is_ok());
+        let err = convert(DropGuardEmpty::new_disarmed()).unwrap_err();
+        assert_eq!(err.downcast_ref::<GuardError>(), Some(&GuardError::NotArmed));
+    }
+}
@@ -0,0 +1,93 @@
+/// A guard that runs a cleanup closure if it is dropped during a [`panic!`], and does nothing
+/// otherwise.
+///
+/// This is the inverse of the other guards. Rather than enforcing that a value is consumed, it
+/// undoes partial work when a scope is left by unwinding, and is simply dropped on success.
+/// Created by [`DropGuardEmpty::panic_only`](super::DropGuardEmpty::panic_only).
+///
+/// # Examples
+///
+/// ```rust
+/// use no_drop::rls::DropGuardEmpty;
+///
+/// let result = std::panic::catch_unwind(|| {
+///     let _rollback = DropGuardEmpty::panic_only(|| eprintln!("rolling back"));
+///     panic!("write failed");
+/// });
+/// assert!(result.is_err());
+/// ```
+#[must_use = "the cleanup can only run while the `PanicGuard` is alive"]
+pub struct PanicGuard<F: FnOnce()>(Option<F>);
+
+impl<F: FnOnce()> PanicGuard<F> {
+    pub(crate) const fn new(cleanup: F) -> Self {
+        Self(Some(cleanup))
+    }
+
+    /// Drops the guard without running the cleanup, even if the thread is panicking.
+    pub fn defuse(mut self) {
+        self.0 = None;
+    }
+}
+
+impl<F: FnOnce()> core::fmt::Debug for PanicGuard<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("PanicGuard").field(&self.0.is_some()).finish()
+    }
+}
+
+impl<F: FnOnce()> Drop for PanicGuard<F> {
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            if let Some(cleanup) = self.0.take() {
+                cleanup();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::catch_unwind;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::guards::DropGuardEmpty;
+
+    #[test]
+    fn normal_exit_runs_nothing() {
+        static CLEANUPS: AtomicUsize = AtomicUsize::new(0);
+        {
+            let _guard = DropGuardEmpty::panic_only(|| {
+                CLEANUPS.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+        assert_eq!(CLEANUPS.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn panicking_scope_runs_cleanup_once() {
+        static CLEANUPS: AtomicUsize = AtomicUsize::new(0);
+        let result = catch_unwind(|| {
+            let _guard = DropGuardEmpty::panic_only(|| {
+                CLEANUPS.fetch_add(1, Ordering::SeqCst);
+            });
+            panic!("failed");
+        });
+        assert!(result.is_err());
+        assert_eq!(CLEANUPS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn defused_guard_skips_cleanup() {
+        static CLEANUPS: AtomicUsize = AtomicUsize::new(0);
+        let result = catch_unwind(|| {
+            let guard = DropGuardEmpty::panic_only(|| {
+                CLEANUPS.fetch_add(1, Ordering::SeqCst);
+            });
+            guard.defuse();
+            panic!("failed");
+        });
+        assert!(result.is_err());
+        assert_eq!(CLEANUPS.load(Ordering::SeqCst), 0);
+    }
+}
@@ -35,33 +35,33 @@ macro_rules! ctor {
 /// Test macro for state transitions (arm/disarm).
 /// Matches on final `armed` or `disarmed` state to handle cleanup.
 macro_rules! transition {
-    ($test_name:ident, $ctor:expr, ($($params:tt)*), $method:ident, $expected:expr, armed_no_panic) => {
+    ($test_name:ident, $ctor:expr, ($($params:tt)*), $method:ident $(($($arg:expr),*))?, $expected:expr, armed_no_panic) => {
         #[test]
         fn $test_name() {
             let mut guard = $ctor($($params)*);
-            let changed = guard.$method();
+            let changed = guard.$method($($($arg),*)?);
             assert_eq!(changed, $expected);
             assert!(guard.armed());
         }
     };
     // Transition ending in armed state - auto-disarms
-    ($test_name:ident, $ctor:expr, ($($params:tt)*), $method:ident, $expected:expr, armed, $panic_msg:literal) => {
+    ($test_name:ident, $ctor:expr, ($($params:tt)*), $method:ident $(($($arg:expr),*))?, $expected:expr, armed, $panic_msg:literal) => {
         #[test]
         #[should_panic(expected = $panic_msg)]
         fn $test_name() {
             let mut guard = $ctor($($params)*);
-            let changed = guard.$method();
+            let changed = guard.$method($($($arg),*)?);
             assert_eq!(changed, $expected);
             assert!(guard.armed());
         }
     };
 
     // Transition ending in disarmed state - safe to drop
-    ($test_name:ident, $ctor:expr, ($($params:tt)*), $method:ident, $expected:expr, disarmed) => {
+    ($test_name:ident, $ctor:expr, ($($params:tt)*), $method:ident $(($($arg:expr),*))?, $expected:expr, disarmed) => {
         #[test]
         fn $test_name() {
             let mut guard = $ctor($($params)*);
-            let changed = guard.$method();
+            let changed = guard.$method($($($arg),*)?);
             assert_eq!(changed, $expected);
             assert!(guard.disarmed());
         }
@@ -89,11 +89,46 @@ macro_rules! try_from {
         fn $test_name_err() {
             let guard = $from_ctor($($params)*);
             let result: Result<$target_type, _> = guard.try_into();
-            assert!(result.is_err());
+            assert!(matches!(result, Err($crate::guards::GuardError::NotArmed)));
+        }
+    };
+
+    // Test failed conversion from a guard whose value was taken
+    ($test_name_err:ident, $from_ctor:expr, ($($params:tt)*), $target_type:ty, taken) => {
+        #[test]
+        fn $test_name_err() {
+            let mut guard = $from_ctor($($params)*);
+            let _ = guard.take();
+            let result: Result<$target_type, _> = guard.try_into();
+            assert!(matches!(result, Err($crate::guards::GuardError::AlreadyConsumed)));
+        }
+    };
+}
+
+macro_rules! into_guard {
+    // Test an armed guard yields its inner guard
+    ($test_name:ident, $from_ctor:expr, ($($params:tt)*), armed) => {
+        #[test]
+        fn $test_name() {
+            let guard = $from_ctor($($params)*);
+            let inner = guard.into_guard();
+            assert!(inner.is_some());
+            // Forget the inner guard to prevent panic on drop
+            inner.unwrap().forget();
+        }
+    };
+
+    // Test a disarmed guard yields nothing
+    ($test_name:ident, $from_ctor:expr, ($($params:tt)*), disarmed) => {
+        #[test]
+        fn $test_name() {
+            let guard = $from_ctor($($params)*);
+            assert!(guard.into_guard().is_none());
         }
     };
 }
 
 pub(crate) use ctor;
+pub(crate) use into_guard;
 pub(crate) use transition;
 pub(crate) use try_from;
@@ -0,0 +1,112 @@
+//! Runtime configuration of how the [`panic!`]ing guards handle values dropped while armed.
+//!
+//! By default, dropping an armed value [`panic!`]s. An application can install a hook with
+//! [`set_on_leak`], which is called with the report of every such drop, and choose with
+//! [`set_leak_policy`] whether the drop then [`panic!`]s, is only logged, or aborts the process.
+//! Both are global, so leak handling is decided once for the whole application, rather than at
+//! each call site.
+//!
+//! This only affects the [`panic!`]ing types, from the `rls` module, and the `dbg` module in debug
+//! builds. The `abort` and `log_only` types always behave as their modules describe.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use no_drop::hooks::{set_leak_policy, set_on_leak, LeakPolicy};
+//! use no_drop::rls::NoDrop;
+//!
+//! set_on_leak(|report| eprintln!("leaked: {report}"));
+//! set_leak_policy(LeakPolicy::LogOnly);
+//!
+//! drop(NoDrop::wrap(42)); // logged, but does not panic
+//! # set_leak_policy(LeakPolicy::Panic);
+//! ```
+
+use core::cell::Cell;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Arc, PoisonError, RwLock};
+
+type Hook = Arc<dyn Fn(&str) + Send + Sync>;
+
+static HOOK: RwLock<Option<Hook>> = RwLock::new(None);
+static POLICY: AtomicU8 = AtomicU8::new(LeakPolicy::Panic as u8);
+
+std::thread_local! {
+    /// Whether the hook is running on this thread, so leaks inside it do not call it again.
+    static IN_HOOK: Cell<bool> = const { Cell::new(false) };
+}
+
+/// What happens after a value is dropped while armed, and the hook has been called.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LeakPolicy {
+    /// [`panic!`] with the report, or print it to stderr if the thread is already panicking.
+    #[default]
+    Panic = 0,
+    /// Drop the value normally. The report is printed to stderr if no hook is installed.
+    LogOnly = 1,
+    /// Print the report to stderr and [`abort`](std::process::abort) the process, whether or not a
+    /// hook is installed.
+    Abort = 2,
+}
+
+/// Installs `hook`, to be called with the report of every value dropped while armed, replacing any
+/// previous hook.
+///
+/// The hook is called before the [`LeakPolicy`] is applied, on the thread the value was dropped on.
+/// Values dropped while armed inside the hook do not call it again. Their reports are printed to
+/// stderr instead, and they never [`panic!`].
+pub fn set_on_leak(hook: impl Fn(&str) + Send + Sync + 'static) {
+    *HOOK.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(hook));
+}
+
+/// Removes the installed hook, if any.
+pub fn clear_on_leak() {
+    *HOOK.write().unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+/// Sets what happens after a value is dropped while armed.
+pub fn set_leak_policy(policy: LeakPolicy) {
+    POLICY.store(policy as u8, Ordering::Relaxed);
+}
+
+/// Returns what happens after a value is dropped while armed.
+#[must_use]
+pub fn leak_policy() -> LeakPolicy {
+    match POLICY.load(Ordering::Relaxed) {
+        1 => LeakPolicy::LogOnly,
+        2 => LeakPolicy::Abort,
+        _ => LeakPolicy::Panic,
+    }
+}
+
+/// Resets [`IN_HOOK`] once the hook returns, or unwinds.
+struct HookScope;
+
+impl Drop for HookScope {
+    fn drop(&mut self) {
+        IN_HOOK.set(false);
+    }
+}
+
+/// Calls the installed hook with `report`, returning the policy to apply.
+pub(crate) fn on_leak(report: &str) -> LeakPolicy {
+    if IN_HOOK.get() {
+        std::eprintln!("{report}");
+        return LeakPolicy::LogOnly;
+    }
+
+    let policy = leak_policy();
+    // The lock is released before the hook runs, so it may replace itself.
+    let hook = HOOK.read().unwrap_or_else(PoisonError::into_inner).clone();
+    match hook {
+        Some(hook) => {
+            IN_HOOK.set(true);
+            let _scope = HookScope;
+            hook(report);
+        }
+        None if policy == LeakPolicy::LogOnly => std::eprintln!("{report}"),
+        None => {}
+    }
+    policy
+}
@@ -1,16 +1,19 @@
-use std::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
 
-use crate::no_drop::{NoDropEmpty, NoDropMsg, NoDropPassEmpty, NoDropPassMsg};
+use crate::no_drop::{NoDropEmpty, NoDropPassEmpty};
+#[cfg(feature = "alloc")]
+use crate::no_drop::{NoDropMsg, NoDropPassMsg};
 
 /// Extension trait for wrapping values in [`NoDropPassEmpty`] or [`NoDropPassMsg`].
 ///
 /// This is the "dbg" version that returns zero-cost passthrough wrappers.
-#[allow(dead_code)]
 pub trait IntoNoDropDbg: Sized {
     /// Wraps this value in a [`NoDropPassEmpty`].
     fn no_drop(self) -> NoDropPassEmpty<Self>;
 
     /// Wraps this value in a [`NoDropPassMsg`] with a custom message.
+    #[cfg(feature = "alloc")]
     fn expect_no_drop<'msg, M: Into<Cow<'msg, str>>>(self, msg: M) -> NoDropPassMsg<'msg, Self>;
 }
 
@@ -19,6 +22,7 @@ impl<T> IntoNoDropDbg for T {
         NoDropPassEmpty::wrap(self)
     }
 
+    #[cfg(feature = "alloc")]
     fn expect_no_drop<'msg, M: Into<Cow<'msg, str>>>(self, msg: M) -> NoDropPassMsg<'msg, Self> {
         NoDropPassMsg::wrap(self, msg)
     }
@@ -27,20 +31,23 @@ impl<T> IntoNoDropDbg for T {
 /// Extension trait for wrapping values in [`NoDropEmpty`] or [`NoDropMsg`].
 ///
 /// This is the "rls" version that always returns panicking wrappers.
-#[allow(dead_code)]
 pub trait IntoNoDropRls: Sized {
     /// Wraps this value in a [`NoDropEmpty`].
     fn no_drop(self) -> NoDropEmpty<Self>;
 
     /// Wraps this value in a [`NoDropMsg`] with a custom `msg`.
+    #[cfg(feature = "alloc")]
     fn expect_no_drop<'msg, M: Into<Cow<'msg, str>>>(self, msg: M) -> NoDropMsg<'msg, Self>;
 }
 
 impl<T> IntoNoDropRls for T {
+    #[track_caller]
     fn no_drop(self) -> NoDropEmpty<Self> {
         NoDropEmpty::wrap(self)
     }
 
+    #[cfg(feature = "alloc")]
+    #[track_caller]
     fn expect_no_drop<'msg, M: Into<Cow<'msg, str>>>(self, msg: M) -> NoDropMsg<'msg, Self> {
         NoDropMsg::wrap(self, msg)
     }
@@ -1,4 +1,10 @@
 #![doc = include_str!("../README.md")]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+// The docs link to the `alloc` types throughout, which only resolve when they are enabled.
+#![cfg_attr(not(feature = "alloc"), allow(rustdoc::broken_intra_doc_links))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 #[warn(clippy::pedantic)]
 #[warn(clippy::cargo)]
@@ -8,55 +14,220 @@ mod into;
 mod markers;
 mod no_drop;
 
+#[cfg(feature = "hooks")]
+pub mod hooks;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(feature = "derive")]
+pub use no_drop_derive::{must_consume, NoDropConsume};
+
+#[cfg(feature = "alloc")]
+pub use crate::no_drop::consume_indexed;
+
+/// Implementation details used by the `derive` macros, and the passthrough types [`dbg`](mod@dbg)
+/// exports in release builds. Not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::guards::DropGuardPassthroughEmpty;
+    pub use crate::into::IntoNoDropDbg;
+    pub use crate::no_drop::{panic_unconsumed, NoDropPassEmpty};
+
+    #[cfg(feature = "alloc")]
+    pub use crate::guards::DropGuardPassthroughMsg;
+    #[cfg(feature = "alloc")]
+    pub use crate::no_drop::consume_indexed_pass;
+    #[cfg(feature = "alloc")]
+    pub use crate::no_drop::NoDropPassMsg;
+    #[cfg(feature = "alloc")]
+    pub use alloc::format;
+}
+
+/// The most commonly used items, re-exported from [`dbg`](mod@dbg).
+///
+/// ```rust
+/// use no_drop::prelude::*;
+///
+/// let value = NoDrop::wrap(42);
+/// assert_eq!(value.consume(), 42);
+/// ```
+pub mod prelude {
+    pub use crate::dbg::{DropGuardEmpty, GuardError, GuardNotArmed, IntoNoDrop, NoDrop};
+
+    #[cfg(feature = "alloc")]
+    pub use crate::dbg::{consume_indexed, DropGuard, NoDropMsg};
+}
+
 /// Module containing [`NoDrop`](no_drop::NoDropEmpty) and [`NoDropMsg`](no_drop::NoDropMsg)
 /// with debug-only panic behavior.
+///
+/// With the `strict-guards` feature, these are the [`panic!`]ing types in every build, regardless of
+/// `debug_assertions`.
+///
+/// In release builds, these names refer to zero-cost passthrough types. The passthrough types, and
+/// the marker types that parameterize them, are not part of the public API, so code that is generic
+/// over them cannot be written. Instead, write code against the names exported here, which compiles
+/// the same in every build.
+///
+/// The passthrough types keep no message, so their message methods compile, but behave differently:
+/// `NoDropMsg::msg` always returns an empty string, and `set_msg` and `map_msg` discard the new
+/// message. Do not rely on reading a message back from a `dbg` type.
+///
+/// Some types and functions have no passthrough counterpart, so they are only exported from
+/// [`rls`](mod@rls), and always [`panic!`] when dropped while armed, even in release builds:
+/// `NoDropIter`, `NoDrop2`, `NoDrop3`, `NoDrop4`, `NoDropArray`, `CheckedNoDrop`, `NoDropResult`,
+/// `NoDropBoxed`, `DropGuardSet`, `drain_consume`, and `remove_and_consume`. Import them from `rls`
+/// where they are needed.
 pub mod dbg {
-    pub use crate::guards::GuardNotArmed;
+    pub use crate::guards::{GuardError, GuardNotArmed, GuardSliceExt};
     pub use crate::no_drop::DEFAULT_DROP_PANIC_MSG;
 
-    #[cfg(debug_assertions)]
+    #[cfg(feature = "std")]
+    pub use crate::guards::{catch_unconsumed, GuardLeaked, PanicGuard};
+    #[cfg(feature = "alloc")]
+    pub use crate::guards::{ArmScope, GuardVecExt};
+
+    #[cfg(feature = "alloc")]
+    pub use crate::no_drop::CANCELLED_DROP_PANIC_MSG;
+
+    #[cfg(any(debug_assertions, feature = "strict-guards"))]
     pub use crate::no_drop::NoDrop;
 
-    #[cfg(not(debug_assertions))]
-    pub use crate::no_drop::NoDropPassEmpty as NoDropEmpty;
+    #[cfg(not(any(debug_assertions, feature = "strict-guards")))]
+    pub use crate::no_drop::NoDropPassEmpty as NoDrop;
 
-    #[cfg(debug_assertions)]
+    #[cfg(any(debug_assertions, feature = "strict-guards"))]
     pub use crate::into::IntoNoDropRls as IntoNoDrop;
 
-    #[cfg(not(debug_assertions))]
+    #[cfg(not(any(debug_assertions, feature = "strict-guards")))]
     pub use crate::into::IntoNoDropDbg as IntoNoDrop;
 
-    #[cfg(debug_assertions)]
+    #[cfg(all(feature = "alloc", any(debug_assertions, feature = "strict-guards")))]
     pub use crate::no_drop::NoDropMsg;
 
-    #[cfg(not(debug_assertions))]
+    #[cfg(all(feature = "alloc", not(any(debug_assertions, feature = "strict-guards"))))]
     pub use crate::no_drop::NoDropPassMsg as NoDropMsg;
 
-    #[cfg(debug_assertions)]
+    #[cfg(feature = "alloc")]
+    #[doc(inline)]
+    pub use crate::__no_drop_msg_dbg as no_drop_msg;
+
+    pub use crate::no_drop::NoDropWith;
+
+    pub use crate::no_drop::with_guard;
+
+    #[cfg(all(feature = "alloc", any(debug_assertions, feature = "strict-guards")))]
+    pub use crate::no_drop::consume_indexed;
+
+    #[cfg(all(feature = "alloc", not(any(debug_assertions, feature = "strict-guards"))))]
+    pub use crate::no_drop::consume_indexed_pass as consume_indexed;
+
+    #[cfg(any(debug_assertions, feature = "strict-guards"))]
     pub use crate::guards::DropGuardEmpty;
 
-    #[cfg(not(debug_assertions))]
+    #[cfg(not(any(debug_assertions, feature = "strict-guards")))]
     pub use crate::guards::DropGuardPassthroughEmpty as DropGuardEmpty;
 
-    #[cfg(debug_assertions)]
+    #[cfg(all(feature = "alloc", any(debug_assertions, feature = "strict-guards")))]
     pub use crate::guards::DropGuard;
 
-    #[cfg(not(debug_assertions))]
+    #[cfg(all(feature = "alloc", not(any(debug_assertions, feature = "strict-guards"))))]
     pub use crate::guards::DropGuardPassthroughMsg as DropGuard;
 }
 
 /// Module containing [`NoDrop`](no_drop::NoDropEmpty) and [`NoDropMsg`](no_drop::NoDropMsg) with always-[`panic!`]ing behavior.
 pub mod rls {
-    pub use crate::guards::GuardNotArmed;
+    pub use crate::guards::{GuardError, GuardNotArmed, GuardSliceExt};
     pub use crate::no_drop::DEFAULT_DROP_PANIC_MSG;
 
+    #[cfg(feature = "std")]
+    pub use crate::guards::{catch_unconsumed, GuardLeaked, PanicGuard};
+    #[cfg(feature = "alloc")]
+    pub use crate::guards::{ArmScope, GuardVecExt};
+
+    #[cfg(feature = "alloc")]
+    pub use crate::no_drop::CANCELLED_DROP_PANIC_MSG;
+
     pub use crate::no_drop::NoDrop;
 
+    pub use crate::no_drop::{NoDrop2, NoDrop3, NoDrop4};
+
+    pub use crate::no_drop::NoDropArray;
+
+    pub use crate::no_drop::{NoDropIter, UNEXHAUSTED_ITER_PANIC_MSG};
+
+    pub use crate::no_drop::CheckedNoDrop;
+
     pub use crate::into::IntoNoDropRls as IntoNoDrop;
 
+    #[cfg(feature = "alloc")]
     pub use crate::no_drop::NoDropMsg;
 
+    #[cfg(feature = "alloc")]
+    #[doc(inline)]
+    pub use crate::__no_drop_msg_rls as no_drop_msg;
+
+    pub use crate::no_drop::NoDropWith;
+
+    pub use crate::no_drop::with_guard;
+
+    #[cfg(feature = "alloc")]
+    pub use crate::no_drop::consume_indexed;
+
+    #[cfg(feature = "std")]
+    pub use crate::no_drop::{drain_consume, remove_and_consume};
+
+    #[cfg(feature = "alloc")]
+    pub use crate::no_drop::NoDropBoxed;
+
+    #[cfg(feature = "alloc")]
+    pub use crate::no_drop::{NoDropResult, UNHANDLED_RESULT_PANIC_MSG};
+
     pub use crate::guards::DropGuardEmpty;
 
+    #[cfg(feature = "alloc")]
     pub use crate::guards::DropGuard;
+
+    #[cfg(feature = "alloc")]
+    pub use crate::guards::DropGuardSet;
+}
+
+/// Module containing [`NoDrop`](no_drop::NoDropAbortEmpty) and [`NoDropMsg`](no_drop::NoDropAbortMsg)
+/// with always-aborting behavior.
+///
+/// Dropping an armed value from this module prints its message to stderr and calls
+/// [`std::process::abort`]. Unlike the [`panic!`]ing types in [`rls`], this cannot be caught or
+/// unwound past, making these types suitable for guarding memory-safety or FFI contracts.
+#[cfg(feature = "std")]
+pub mod abort {
+    pub use crate::guards::{GuardError, GuardNotArmed, GuardSliceExt};
+    pub use crate::no_drop::DEFAULT_DROP_PANIC_MSG;
+
+    #[cfg(feature = "alloc")]
+    pub use crate::guards::GuardVecExt;
+
+    pub use crate::no_drop::NoDropAbortEmpty as NoDrop;
+
+    pub use crate::no_drop::NoDropAbortMsg as NoDropMsg;
+
+    pub use crate::guards::DropGuardAbortEmpty as DropGuardEmpty;
+
+    pub use crate::guards::DropGuardAbortMsg as DropGuard;
+}
+
+/// Module containing [`NoDrop`](no_drop::NoDropLogEmpty) and [`NoDropMsg`](no_drop::NoDropLogMsg)
+/// with logging, non-[`panic!`]ing behavior.
+///
+/// Dropping an armed value from this module emits a [`tracing`] error event with its message and
+/// creation location, then drops the value normally. This allows choosing a softer severity for
+/// some builds, such as long-running production services, by swapping the module imported.
+#[cfg(feature = "tracing")]
+pub mod log_only {
+    pub use crate::no_drop::DEFAULT_DROP_PANIC_MSG;
+
+    pub use crate::no_drop::NoDropLogEmpty as NoDrop;
+
+    pub use crate::no_drop::NoDropLogMsg as NoDropMsg;
 }
@@ -2,7 +2,9 @@ use sealed::sealed;
 
 /// Sealed trait for passthrough marker types.
 ///
-/// This trait is sealed and cannot be implemented outside this crate.
+/// This trait is sealed and cannot be implemented outside this crate. It is also not reachable
+/// from outside this crate, since the passthrough types it parameterizes only stand in for the
+/// [`dbg`](crate::dbg) types in release builds, and are not a stable surface of their own.
 #[sealed]
 pub trait PassMarker {}
 
@@ -14,8 +16,10 @@ pub struct Empty;
 impl PassMarker for Empty {}
 
 /// Marker type for passthrough types with custom message.
+#[cfg(feature = "alloc")]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Msg;
 
+#[cfg(feature = "alloc")]
 #[sealed]
 impl PassMarker for Msg {}
@@ -0,0 +1,38 @@
+//! A global count of guarded values dropped while armed.
+//!
+//! Every drop that would [`panic!`], or with the `tracing` feature be logged by the `log_only`
+//! types, increments the count first. This makes forgotten values observable even when their
+//! [`panic!`]s are caught, such as in a long-running integration test.
+
+use core::sync::atomic::{AtomicU64, Ordering};
+
+static ARMED_DROPS: AtomicU64 = AtomicU64::new(0);
+
+/// Returns the number of guarded values dropped while armed, since the program started or the last
+/// [`reset_armed_drops`].
+///
+/// # Examples
+///
+/// ```rust
+/// use no_drop::metrics::{armed_drops, reset_armed_drops};
+/// use no_drop::rls::NoDrop;
+///
+/// reset_armed_drops();
+/// let result = std::panic::catch_unwind(|| drop(NoDrop::wrap(42)));
+/// assert!(result.is_err());
+/// assert_eq!(armed_drops(), 1);
+/// ```
+#[must_use]
+pub fn armed_drops() -> u64 {
+    ARMED_DROPS.load(Ordering::Relaxed)
+}
+
+/// Resets the count of guarded values dropped while armed to zero.
+pub fn reset_armed_drops() {
+    ARMED_DROPS.store(0, Ordering::Relaxed);
+}
+
+/// Records a guarded value dropped while armed.
+pub(crate) fn record_armed_drop() {
+    ARMED_DROPS.fetch_add(1, Ordering::Relaxed);
+}
@@ -0,0 +1,114 @@
+use super::leak::panic_with;
+use super::{Origin, DEFAULT_DROP_PANIC_MSG};
+use crate::guards::GuardError;
+
+/// A wrapper that is consumed through `&mut self`, detecting attempts to consume it twice.
+///
+/// [`NoDrop`](super::NoDropEmpty) is consumed by value, so the compiler already prevents a second
+/// consume. Code that can only reach the wrapper through a `&mut` reference, such as through
+/// interior mutability, cannot do that. This wrapper holds its value until the first
+/// [`Self::consume`], which returns it. Any later attempt returns [`None`] instead.
+///
+/// Dropping the wrapper before it is consumed [`panic!`]s with [`DEFAULT_DROP_PANIC_MSG`], and the
+/// location the wrapper was created at. Once consumed, it may be dropped freely.
+///
+/// # Examples
+///
+/// ```rust
+/// use no_drop::rls::CheckedNoDrop;
+///
+/// let mut wrapper = CheckedNoDrop::wrap(42);
+/// assert_eq!(wrapper.consume(), Some(42));
+/// assert_eq!(wrapper.consume(), None);
+/// ```
+#[derive(Debug)]
+#[must_use = "the value must be taken, or it will panic when dropped"]
+pub struct CheckedNoDrop<T> {
+    value: Option<T>,
+    origin: Origin,
+}
+
+impl<T> CheckedNoDrop<T> {
+    /// Creates a new wrapper around `value`.
+    #[track_caller]
+    pub const fn wrap(value: T) -> Self {
+        Self { value: Some(value), origin: Origin::caller() }
+    }
+
+    /// Takes the value out of the wrapper, or returns [`None`] if it was already consumed.
+    #[inline]
+    pub fn consume(&mut self) -> Option<T> {
+        self.value.take()
+    }
+
+    /// Takes the value out of the wrapper.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GuardError::AlreadyConsumed`] if the value was already consumed.
+    #[inline]
+    pub fn try_consume(&mut self) -> Result<T, GuardError> {
+        self.consume().ok_or(GuardError::AlreadyConsumed)
+    }
+
+    /// Returns whether the value was consumed.
+    #[inline]
+    #[must_use]
+    pub const fn consumed(&self) -> bool {
+        self.value.is_none()
+    }
+
+    /// Returns a reference to the value, or [`None`] if it was consumed.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> Option<&T> {
+        self.value.as_ref()
+    }
+
+    /// Returns a mutable reference to the value, or [`None`] if it was consumed.
+    #[inline]
+    #[must_use]
+    pub fn inner_mut(&mut self) -> Option<&mut T> {
+        self.value.as_mut()
+    }
+}
+
+impl<T> Drop for CheckedNoDrop<T> {
+    /// [`panic!`]s if the value was not consumed, unless the thread is already panicking.
+    #[track_caller]
+    fn drop(&mut self) {
+        if self.value.is_some() {
+            panic_with(DEFAULT_DROP_PANIC_MSG, self.origin);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_consume_succeeds() {
+        let mut wrapper = CheckedNoDrop::wrap(42);
+        assert!(!wrapper.consumed());
+        assert_eq!(wrapper.inner(), Some(&42));
+        assert_eq!(wrapper.consume(), Some(42));
+        assert!(wrapper.consumed());
+    }
+
+    #[test]
+    fn second_consume_returns_none() {
+        let mut wrapper = CheckedNoDrop::wrap(42);
+        assert_eq!(wrapper.try_consume(), Ok(42));
+        assert_eq!(wrapper.consume(), None);
+        assert_eq!(wrapper.try_consume(), Err(GuardError::AlreadyConsumed));
+        assert_eq!(wrapper.inner_mut(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn drop_without_consume_panics() {
+        let wrapper = CheckedNoDrop::wrap(42);
+        drop(wrapper);
+    }
+}
@@ -0,0 +1,140 @@
+#[cfg(feature = "std")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "std")]
+use core::cell::Cell;
+use core::fmt;
+
+use super::Origin;
+#[cfg(feature = "alloc")]
+use super::Trace;
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    /// The report of the last guard [`panic!`] on this thread, so it can be told apart from other
+    /// panics once caught.
+    static LAST_REPORT: Cell<Option<String>> = const { Cell::new(None) };
+}
+
+/// Takes the report of the last guard [`panic!`] on this thread, if any.
+#[cfg(feature = "std")]
+pub(crate) fn take_last_report() -> Option<String> {
+    LAST_REPORT.take()
+}
+
+/// Prints a notice to stderr that an armed value created at `origin` was forcibly forgotten.
+///
+/// This is only a breadcrumb for finding leaks papered over with `forget`, so it never
+/// [`panic!`]s, and is only compiled into debug builds.
+#[cfg(all(feature = "std", debug_assertions))]
+#[cold]
+#[inline(never)]
+pub(crate) fn notice_forgotten(msg: &dyn fmt::Display, origin: Origin) {
+    std::eprintln!("armed value forgotten (created at {origin}): {msg}");
+}
+
+/// [`panic!`]s with `msg`, reporting an armed value created at `origin` that was dropped.
+///
+/// If the thread is already panicking, the report is printed to stderr instead, since a second
+/// [`panic!`] during an unwind would abort the process. Without `std` the thread state is unknown,
+/// so this always [`panic!`]s. With the `tracing` feature, a `tracing` error event with the same
+/// report is emitted first. With the `hooks` feature, the installed hook is called with the report,
+/// and the leak policy decides whether to [`panic!`]. With the `testing` feature, this does nothing
+/// at all while the guards are made inert by a `DisableGuard`, so the drop is neither counted,
+/// logged, nor passed to the hook.
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn panic_with(msg: &str, origin: Origin) {
+    report(format_args!("{msg} (created at {origin})"));
+}
+
+/// [`panic!`]s like [`panic_with`], with a formatted `msg`.
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn panic_with_args(msg: fmt::Arguments<'_>, origin: Origin) {
+    report(format_args!("{msg} (created at {origin})"));
+}
+
+/// [`panic!`]s like [`panic_with`], appending the backtrace of where the value was created.
+///
+/// The backtrace is only captured with the `backtrace` feature, without it this is identical to
+/// [`panic_with`]. `msg` is only formatted if the report is emitted, so a lazy message is not
+/// computed while the guards are inert.
+#[cfg(feature = "alloc")]
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn panic_with_trace(msg: &dyn fmt::Display, origin: Origin, trace: &Trace) {
+    report(format_args!("{msg} (created at {origin}){trace}"));
+}
+
+/// [`panic!`]s with `msg`, reporting a `NoDropConsume` value that was dropped.
+///
+/// Called by the code generated by the `NoDropConsume` derive. Behaves like [`panic_with`], but
+/// without an origin, since derived types do not record one.
+#[doc(hidden)]
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub fn panic_unconsumed(msg: &str) {
+    report(format_args!("{msg}"));
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn report(report: fmt::Arguments<'_>) {
+    #[cfg(feature = "testing")]
+    if crate::testing::guards_inert() {
+        return;
+    }
+
+    #[cfg(feature = "metrics")]
+    crate::metrics::record_armed_drop();
+
+    #[cfg(feature = "std")]
+    let report = &render(report);
+
+    #[cfg(feature = "tracing")]
+    tracing::error!("{report}");
+
+    #[cfg(feature = "hooks")]
+    match crate::hooks::on_leak(report) {
+        crate::hooks::LeakPolicy::Panic => {}
+        crate::hooks::LeakPolicy::LogOnly => return,
+        crate::hooks::LeakPolicy::Abort => {
+            std::eprintln!("{report}");
+            std::process::abort();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    if std::thread::panicking() {
+        std::eprintln!("{report}");
+        return;
+    }
+
+    #[cfg(feature = "std")]
+    {
+        LAST_REPORT.set(Some(report.clone()));
+        panic!("{report}");
+    }
+
+    #[cfg(not(feature = "std"))]
+    panic!("{report}");
+}
+
+/// Formats `report`, which may compute a lazy message.
+///
+/// If the thread is already panicking, a [`panic!`] while formatting is caught, and a fixed report
+/// used instead, since letting it escape the drop would abort the process.
+#[cfg(feature = "std")]
+fn render(report: fmt::Arguments<'_>) -> String {
+    if !std::thread::panicking() {
+        return report.to_string();
+    }
+
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| report.to_string()))
+        .unwrap_or_else(|_| String::from("armed value dropped during a panic, and its message panicked"))
+}
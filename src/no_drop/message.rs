@@ -0,0 +1,98 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::sync::Arc;
+use core::fmt;
+use core::panic::RefUnwindSafe;
+
+/// The panic message of a [`NoDropMsg`](super::NoDropMsg), either provided up front or computed
+/// on demand.
+///
+/// Borrowed text, including any `&'static str`, is held as [`Self::Borrowed`] and never allocates,
+/// even as it moves between a guard's armed and disarmed states.
+///
+/// Comparisons and formatting operate on the resolved message text, computing a [`Self::Lazy`]
+/// message as needed.
+#[derive(Clone)]
+pub(crate) enum Message<'msg> {
+    Borrowed(&'msg str),
+    Owned(String),
+    Lazy(Arc<dyn Fn() -> String + Send + Sync + RefUnwindSafe + 'msg>),
+}
+
+impl<'msg> Message<'msg> {
+    /// Returns the message text, computing it if [`Self::Lazy`].
+    pub(crate) fn resolve(&self) -> Cow<'_, str> {
+        match self {
+            Self::Borrowed(msg) => Cow::Borrowed(msg),
+            Self::Owned(msg) => Cow::Borrowed(msg),
+            Self::Lazy(f) => Cow::Owned(f()),
+        }
+    }
+
+    /// Creates a message from `args`, borrowing it if it is a plain string literal, and formatting
+    /// it otherwise.
+    pub(crate) fn from_args(args: fmt::Arguments<'_>) -> Self {
+        match args.as_str() {
+            Some(msg) => Self::Borrowed(msg),
+            None => Self::Owned(alloc::fmt::format(args)),
+        }
+    }
+
+    /// Consumes the message, returning its text, computing it if [`Self::Lazy`].
+    pub(crate) fn into_cow(self) -> Cow<'msg, str> {
+        match self {
+            Self::Borrowed(msg) => Cow::Borrowed(msg),
+            Self::Owned(msg) => Cow::Owned(msg),
+            Self::Lazy(f) => Cow::Owned(f()),
+        }
+    }
+}
+
+impl Default for Message<'_> {
+    fn default() -> Self {
+        Self::Borrowed("")
+    }
+}
+
+impl<'msg> From<Cow<'msg, str>> for Message<'msg> {
+    fn from(msg: Cow<'msg, str>) -> Self {
+        match msg {
+            Cow::Borrowed(msg) => Self::Borrowed(msg),
+            Cow::Owned(msg) => Self::Owned(msg),
+        }
+    }
+}
+
+impl fmt::Display for Message<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.resolve())
+    }
+}
+
+impl fmt::Debug for Message<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.resolve(), f)
+    }
+}
+
+impl PartialEq for Message<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.resolve() == other.resolve()
+    }
+}
+
+impl Eq for Message<'_> {}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Message<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.resolve())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Message<'_> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::Owned)
+    }
+}
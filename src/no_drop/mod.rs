@@ -1,15 +1,78 @@
 #[cfg(test)]
 mod test_macros;
 
+mod checked_no_drop;
+mod leak;
+#[cfg(feature = "alloc")]
+mod message;
+#[cfg(feature = "std")]
+mod no_drop_abort;
+mod no_drop_array;
+#[cfg(feature = "alloc")]
+mod no_drop_boxed;
 mod no_drop_empty;
+#[cfg(feature = "alloc")]
+mod no_drop_fn;
+mod no_drop_iter;
+#[cfg(feature = "alloc")]
+mod no_drop_len;
+#[cfg(feature = "tracing")]
+mod no_drop_log;
+#[cfg(feature = "std")]
+mod no_drop_map;
+#[cfg(feature = "alloc")]
 mod no_drop_msg;
 mod no_drop_pass;
+#[cfg(feature = "alloc")]
+mod no_drop_result;
+mod no_drop_tuple;
+#[cfg(feature = "alloc")]
+mod no_drop_vec;
+mod no_drop_with;
+mod origin;
+#[cfg(feature = "alloc")]
+mod trace;
 
+pub use checked_no_drop::CheckedNoDrop;
+#[cfg(all(feature = "std", debug_assertions))]
+pub(crate) use leak::notice_forgotten;
+pub use leak::panic_unconsumed;
+#[cfg(feature = "alloc")]
+pub(crate) use leak::panic_with;
+#[cfg(feature = "std")]
+pub(crate) use leak::take_last_report;
+#[cfg(feature = "alloc")]
+pub(crate) use message::Message;
+#[cfg(feature = "std")]
+pub use no_drop_abort::{NoDropAbortEmpty, NoDropAbortMsg};
+pub use no_drop_array::NoDropArray;
+#[cfg(feature = "alloc")]
+pub use no_drop_boxed::NoDropBoxed;
 pub use no_drop_empty::NoDropEmpty as NoDrop;
 pub(crate) use no_drop_empty::NoDropEmpty;
-pub use no_drop_empty::DEFAULT_DROP_PANIC_MSG;
-pub use no_drop_msg::NoDropMsg;
+pub use no_drop_empty::{with_guard, DEFAULT_DROP_PANIC_MSG};
+pub use no_drop_iter::{NoDropIter, UNEXHAUSTED_ITER_PANIC_MSG};
+#[cfg(feature = "tracing")]
+pub use no_drop_log::{NoDropLogEmpty, NoDropLogMsg};
+#[cfg(feature = "std")]
+pub use no_drop_map::{drain_consume, remove_and_consume};
+#[cfg(feature = "alloc")]
+pub use no_drop_msg::{NoDropMsg, CANCELLED_DROP_PANIC_MSG};
+#[cfg(feature = "alloc")]
+pub use no_drop_pass::consume_indexed_pass;
+#[cfg(feature = "alloc")]
+pub use no_drop_result::{NoDropResult, UNHANDLED_RESULT_PANIC_MSG};
+pub use no_drop_tuple::{NoDrop2, NoDrop3, NoDrop4};
+#[cfg(feature = "alloc")]
+pub use no_drop_vec::consume_indexed;
+pub use no_drop_with::NoDropWith;
+pub(crate) use origin::Origin;
+#[cfg(feature = "alloc")]
+pub(crate) use trace::Trace;
 
 // Type aliases for passthrough variants
-pub(crate) type NoDropPassEmpty<T = ()> = no_drop_pass::NoDropPass<'static, crate::markers::Empty, T>;
-pub(crate) type NoDropPassMsg<'msg, T = ()> = no_drop_pass::NoDropPass<'msg, crate::markers::Msg, T>;
+#[doc(hidden)]
+pub type NoDropPassEmpty<T = ()> = no_drop_pass::NoDropPass<'static, crate::markers::Empty, T>;
+#[doc(hidden)]
+#[cfg(feature = "alloc")]
+pub type NoDropPassMsg<'msg, T = ()> = no_drop_pass::NoDropPass<'msg, crate::markers::Msg, T>;
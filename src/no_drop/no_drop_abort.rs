@@ -0,0 +1,226 @@
+use alloc::borrow::Cow;
+use core::mem::ManuallyDrop;
+
+use super::{Origin, DEFAULT_DROP_PANIC_MSG};
+
+/// Prints `msg` and the `origin` of the dropped value to stderr and aborts the process.
+#[cold]
+#[inline(never)]
+fn abort_with(msg: &str, origin: Origin) -> ! {
+    eprintln!("{msg} (created at {origin})");
+    std::process::abort()
+}
+
+/// A wrapper around a `T` value that always aborts the process if dropped without being
+/// [`Self::consume`]d or [`Self::forget`]ten.
+///
+/// Unlike [`NoDropEmpty`](super::NoDropEmpty), dropping this type does not [`panic!`], so it cannot
+/// be caught or unwound past, making it suitable for guarding memory-safety or FFI invariants.
+#[derive(
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Debug,
+    derive_more::Deref,
+    derive_more::DerefMut,
+    derive_more::AsMut,
+    derive_more::AsRef,
+)]
+#[must_use = "the value must be consumed, or it will abort the process when dropped"]
+pub struct NoDropAbortEmpty<T = ()>(
+    #[deref]
+    #[deref_mut]
+    #[as_mut]
+    #[as_ref]
+    T,
+    Origin,
+);
+
+impl<T> NoDropAbortEmpty<T> {
+    /// Creates a new wrapper around `value`.
+    #[track_caller]
+    pub fn wrap(value: T) -> Self {
+        Self(value, Origin::caller())
+    }
+
+    /// Consumes the wrapper and returns the inner `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::abort::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(42);
+    /// assert_eq!(wrapper.consume(), 42);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn consume(self) -> T {
+        let this = ManuallyDrop::new(self);
+        // SAFETY: `T` is moved out of the wrapper exactly once, then this type is dropped.
+        // No uninitialized access can occur.
+        unsafe { core::ptr::read(&raw const this.0) }
+    }
+
+    /// Consumes the wrapper and returns the inner `T`.
+    #[deprecated(since = "0.3.0", note = "renamed to `consume`")]
+    #[inline]
+    #[must_use]
+    pub fn unwrap(self) -> T {
+        self.consume()
+    }
+
+    /// Forgets this guard, safely dropping it.
+    #[inline]
+    pub fn forget(self) {
+        drop(self.consume());
+    }
+}
+
+impl NoDropAbortEmpty<()> {
+    /// Creates a new empty [`NoDropAbortEmpty`] guard.
+    #[track_caller]
+    pub const fn new() -> Self {
+        Self((), Origin::caller())
+    }
+}
+
+impl Default for NoDropAbortEmpty<()> {
+    #[track_caller]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for NoDropAbortEmpty<()> {
+    fn clone(&self) -> Self {
+        Self((), self.1)
+    }
+}
+
+impl<T> Drop for NoDropAbortEmpty<T> {
+    /// Prints [`DEFAULT_DROP_PANIC_MSG`] to stderr and aborts the process.
+    fn drop(&mut self) {
+        abort_with(DEFAULT_DROP_PANIC_MSG, self.1);
+    }
+}
+
+/// A wrapper around a `T` `value` with a custom `msg` that aborts the process if dropped without
+/// being [`Self::consume`]d or [`Self::forget`]ten.
+///
+/// Unlike [`NoDropMsg`](super::NoDropMsg), dropping this type does not [`panic!`], so it cannot
+/// be caught or unwound past. The `msg` is printed to stderr before aborting.
+#[derive(
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Debug,
+    derive_more::Deref,
+    derive_more::DerefMut,
+    derive_more::AsMut,
+    derive_more::AsRef,
+)]
+#[must_use = "the value must be consumed, or it will abort the process when dropped"]
+pub struct NoDropAbortMsg<'msg, T = ()> {
+    #[deref]
+    #[deref_mut]
+    #[as_mut]
+    #[as_ref]
+    value: T,
+    msg: Cow<'msg, str>,
+    origin: Origin,
+}
+
+impl<'msg, T> NoDropAbortMsg<'msg, T> {
+    /// Creates a new wrapper around `value` with a custom abort `msg`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::abort::NoDropMsg;
+    ///
+    /// let wrapper = NoDropMsg::wrap(42, "forgot to release this buffer");
+    /// assert_eq!(wrapper.consume(), 42);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn wrap<M: Into<Cow<'msg, str>>>(value: T, msg: M) -> Self {
+        Self { value, msg: msg.into(), origin: Origin::caller() }
+    }
+
+    /// Consumes the wrapper and returns the inner `T`.
+    #[inline]
+    #[must_use]
+    pub fn consume(self) -> T {
+        let this = ManuallyDrop::new(self);
+        // SAFETY: `T` and `msg` are each moved out of the wrapper exactly once, then this is
+        // dropped. No uninitialized access can occur.
+        let (value, _msg) = unsafe { (core::ptr::read(&raw const this.value), core::ptr::read(&raw const this.msg)) };
+        value
+    }
+
+    /// Consumes the wrapper and returns the inner `T`.
+    #[deprecated(since = "0.3.0", note = "renamed to `consume`")]
+    #[inline]
+    #[must_use]
+    pub fn unwrap(self) -> T {
+        self.consume()
+    }
+
+    /// Forgets this guard, safely dropping it.
+    #[inline]
+    pub fn forget(self) {
+        drop(self.consume());
+    }
+}
+
+impl<'msg> NoDropAbortMsg<'msg, ()> {
+    /// Creates a new empty [`NoDropAbortMsg`] guard with a custom abort `msg`.
+    #[track_caller]
+    pub fn guard<M: Into<Cow<'msg, str>>>(msg: M) -> Self {
+        Self { value: (), msg: msg.into(), origin: Origin::caller() }
+    }
+
+    /// Consumes the guard and returns the inner abort message.
+    pub(crate) fn unwrap_msg(self) -> Cow<'msg, str> {
+        let this = ManuallyDrop::new(self);
+        // SAFETY: `msg` is moved out of the wrapper exactly once, then this is dropped.
+        // No uninitialized access can occur.
+        unsafe { core::ptr::read(&raw const this.msg) }
+    }
+}
+
+impl<'msg> Clone for NoDropAbortMsg<'msg, ()> {
+    fn clone(&self) -> Self {
+        Self { value: (), msg: self.msg.clone(), origin: self.origin }
+    }
+}
+
+impl<'msg, T> Drop for NoDropAbortMsg<'msg, T> {
+    /// Prints `msg` to stderr and aborts the process.
+    fn drop(&mut self) {
+        abort_with(&self.msg, self.origin);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::no_drop::test_macros::{test_clone, test_ctor, test_forget};
+
+    test_ctor!(abort_empty_wrap, NoDropAbortEmpty::wrap, (42), 42);
+    test_ctor!(abort_empty_new, NoDropAbortEmpty::new, (), ());
+    test_ctor!(abort_empty_default, NoDropAbortEmpty::default, (), ());
+    test_ctor!(abort_msg_wrap, NoDropAbortMsg::wrap, (42, "message"), 42);
+    test_ctor!(abort_msg_guard, NoDropAbortMsg::guard, ("message"), ());
+
+    test_clone!(abort_empty_clone, NoDropAbortEmpty, NoDropAbortEmpty::new, ());
+    test_clone!(abort_msg_clone, NoDropAbortMsg, NoDropAbortMsg::guard, ("message"));
+
+    test_forget!(abort_empty_forget, NoDropAbortEmpty::wrap, (42));
+    test_forget!(abort_msg_forget, NoDropAbortMsg::wrap, (42, "message"));
+}
@@ -0,0 +1,193 @@
+use core::fmt;
+
+use super::leak::panic_with_args;
+use super::{NoDropEmpty, Origin};
+
+/// Forwarding methods for guarded arrays.
+///
+/// All methods leave the guard armed, unless noted otherwise.
+impl<T, const N: usize> NoDropEmpty<[T; N]> {
+    /// Reverses the order of the elements in the guarded array, in place.
+    pub fn reverse(&mut self) {
+        <[T]>::reverse(self.as_mut_slice());
+    }
+
+    /// Converts the guard into a [`NoDropArray`], whose elements can be taken out one at a time.
+    ///
+    /// The new guard reports the same creation location.
+    pub fn into_elements(self) -> NoDropArray<T, N> {
+        let (array, origin) = self.into_parts();
+        NoDropArray { slots: array.map(Some), remaining: N, armed: true, origin }
+    }
+}
+
+/// A guarded array whose elements can be taken out individually, while the guard stays armed for
+/// the rest.
+///
+/// Dropping the guard while any element has not been taken [`panic!`]s, naming how many are left,
+/// and the location the guard was created at. Once every element has been taken, it may be dropped
+/// freely.
+///
+/// # Examples
+///
+/// ```rust
+/// use no_drop::rls::NoDropArray;
+///
+/// let mut buffers = NoDropArray::wrap(["a", "b", "c"]);
+/// assert_eq!(buffers.take_element(1), Some("b"));
+/// assert_eq!(buffers.take_element(1), None);
+/// assert_eq!(buffers.remaining(), 2);
+/// assert_eq!(buffers.consume_remaining(), [Some("a"), None, Some("c")]);
+/// ```
+#[must_use = "the elements must be taken, or it will panic when dropped"]
+pub struct NoDropArray<T, const N: usize> {
+    slots: [Option<T>; N],
+    remaining: usize,
+    armed: bool,
+    origin: Origin,
+}
+
+impl<T, const N: usize> NoDropArray<T, N> {
+    /// Creates a new guard around `array`.
+    #[track_caller]
+    pub fn wrap(array: [T; N]) -> Self {
+        Self { slots: array.map(Some), remaining: N, armed: true, origin: Origin::caller() }
+    }
+
+    /// Creates a new guard around `array` that never panics when dropped, for the passthrough
+    /// wrappers.
+    #[track_caller]
+    pub(crate) fn wrap_inert(array: [T; N]) -> Self {
+        Self { slots: array.map(Some), remaining: N, armed: false, origin: Origin::caller() }
+    }
+
+    /// Takes the element at `index` out of the guard.
+    ///
+    /// Returns [`None`] if the element was already taken, or `index` is out of bounds.
+    pub fn take_element(&mut self, index: usize) -> Option<T> {
+        let element = self.slots.get_mut(index)?.take()?;
+        self.remaining -= 1;
+        Some(element)
+    }
+
+    /// Returns a reference to the element at `index`, if it has not been taken.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.slots.get(index)?.as_ref()
+    }
+
+    /// Returns the number of elements that have not been taken.
+    #[must_use]
+    pub const fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// Consumes the guard, returning the whole array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any element was already taken, dropping the rest. Use [`Self::consume_remaining`]
+    /// instead, if some may have been.
+    #[track_caller]
+    #[must_use]
+    pub fn consume(self) -> [T; N] {
+        let taken = N - self.remaining;
+        let elements = self.consume_remaining();
+        assert!(taken == 0, "{taken} of {N} elements were already taken");
+        elements.map(|element| element.expect("every element is present"))
+    }
+
+    /// Consumes the guard, returning every element that has not been taken, in place.
+    #[must_use]
+    pub fn consume_remaining(mut self) -> [Option<T>; N] {
+        self.remaining = 0;
+        core::mem::replace(&mut self.slots, [const { None }; N])
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for NoDropArray<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NoDropArray").field("slots", &self.slots).field("origin", &self.origin).finish()
+    }
+}
+
+impl<T, const N: usize> Drop for NoDropArray<T, N> {
+    fn drop(&mut self) {
+        if self.armed && self.remaining > 0 {
+            panic_with_args(
+                format_args!("{} of {N} elements were dropped without being taken", self.remaining),
+                self.origin,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reverse_array() {
+        let mut wrapper = NoDropEmpty::wrap([1, 2, 3]);
+        wrapper.reverse();
+        assert_eq!(wrapper.consume(), [3, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn reverse_array_stays_armed() {
+        let mut wrapper = NoDropEmpty::wrap([1, 2, 3]);
+        wrapper.reverse();
+        drop(wrapper);
+    }
+
+    #[test]
+    fn take_some_then_consume_remaining() {
+        let mut elements = NoDropEmpty::wrap([1, 2, 3, 4]).into_elements();
+        assert_eq!(elements.take_element(0), Some(1));
+        assert_eq!(elements.take_element(2), Some(3));
+        assert_eq!(elements.take_element(2), None);
+        assert_eq!(elements.take_element(4), None);
+        assert_eq!(elements.get(1), Some(&2));
+        assert_eq!(elements.get(2), None);
+        assert_eq!(elements.remaining(), 2);
+        assert_eq!(elements.consume_remaining(), [None, Some(2), None, Some(4)]);
+    }
+
+    #[test]
+    fn take_every_element_disarms() {
+        let mut elements = NoDropArray::wrap([1, 2]);
+        assert_eq!(elements.take_element(1), Some(2));
+        assert_eq!(elements.take_element(0), Some(1));
+        drop(elements);
+    }
+
+    #[test]
+    fn consume_whole_array() {
+        let elements = NoDropArray::wrap([1, 2, 3]);
+        assert_eq!(elements.consume(), [1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "1 of 3 elements were already taken")]
+    fn consume_after_take_panics() {
+        let mut elements = NoDropArray::wrap([1, 2, 3]);
+        let _ = elements.take_element(0);
+        let _ = elements.consume();
+    }
+
+    #[test]
+    #[should_panic(expected = "2 of 3 elements were dropped without being taken")]
+    fn drop_with_leftovers_panics() {
+        let mut elements = NoDropArray::wrap([1, 2, 3]);
+        let _ = elements.take_element(1);
+        drop(elements);
+    }
+
+    #[test]
+    fn inert_drops_with_leftovers() {
+        let mut elements = NoDropArray::wrap_inert([1, 2, 3]);
+        assert_eq!(elements.take_element(1), Some(2));
+        drop(elements);
+    }
+}
@@ -0,0 +1,52 @@
+use alloc::boxed::Box;
+
+use super::NoDropEmpty;
+
+/// A [`NoDropEmpty`] guarding a boxed, possibly unsized, value, such as a `dyn Trait` or `[T]`.
+///
+/// [`NoDropEmpty`] stores its value inline, so requires it to be [`Sized`]. Guarding the [`Box`]
+/// instead lifts that restriction, with [`NoDropEmpty::consume`] returning the [`Box`].
+///
+/// # Examples
+///
+/// ```rust
+/// use no_drop::rls::NoDropBoxed;
+///
+/// let callback: NoDropBoxed<dyn Fn() -> i32> = NoDropBoxed::from(Box::new(|| 42) as Box<dyn Fn() -> i32>);
+/// assert_eq!(callback(), 42);
+/// let callback: Box<dyn Fn() -> i32> = callback.consume();
+/// ```
+pub type NoDropBoxed<T> = NoDropEmpty<Box<T>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consume_boxed_fn() {
+        let wrapper: NoDropBoxed<dyn Fn() -> i32> = NoDropBoxed::from(Box::new(|| 42) as Box<dyn Fn() -> i32>);
+        assert_eq!(wrapper(), 42);
+        let callback = wrapper.consume();
+        assert_eq!(callback(), 42);
+    }
+
+    #[test]
+    fn consume_boxed_slice() {
+        let wrapper: NoDropBoxed<[i32]> = NoDropBoxed::wrap(Box::from([1, 2, 3]));
+        assert_eq!(wrapper.len(), 3);
+        assert_eq!(&*wrapper.consume(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn forget_boxed_fn() {
+        let wrapper: NoDropBoxed<dyn Fn()> = NoDropBoxed::from(Box::new(|| ()) as Box<dyn Fn()>);
+        wrapper.forget();
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn drop_boxed_fn_panics() {
+        let wrapper: NoDropBoxed<dyn Fn()> = NoDropBoxed::from(Box::new(|| ()) as Box<dyn Fn()>);
+        drop(wrapper);
+    }
+}
@@ -1,9 +1,29 @@
-use std::mem::ManuallyDrop;
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, boxed::Box};
+use core::borrow::{Borrow, BorrowMut};
+use core::cmp::Ordering;
+use core::fmt;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::pin::Pin;
 
-pub const DEFAULT_DROP_PANIC_MSG: &str = "Value was dropped without being unwrapped";
+use super::leak::panic_with;
+use super::Origin;
+#[cfg(feature = "alloc")]
+use super::{Message, NoDropMsg};
+
+pub const DEFAULT_DROP_PANIC_MSG: &str = "Value was dropped without being consumed";
 
 /// A wrapper around a `T` value that always [`panic!`]s if dropped without being
-/// [`Self::unwrap`]ed or [`Self::forget`]ten.
+/// [`Self::consume`]d or [`Self::forget`]ten.
+///
+/// The location the wrapper was created at is reported in the [`panic!`] message, to help find the
+/// missing consumption. It is ignored by comparisons and hashing.
+///
+/// With the `serde` feature, this type (de)serializes transparently as `T`. A deserialized value
+/// is armed, and must be consumed like any other.
+///
+/// The wrapper is [`Send`], [`Sync`], [`Unpin`], and unwind safe whenever `T` is, and is covariant in
+/// `T`.
 #[derive(
     PartialEq,
     Eq,
@@ -16,13 +36,83 @@ pub const DEFAULT_DROP_PANIC_MSG: &str = "Value was dropped without being unwrap
     derive_more::AsMut,
     derive_more::AsRef,
 )]
-#[must_use]
-pub struct NoDropEmpty<T = ()>(T);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+#[must_use = "the value must be consumed, or it will panic when dropped"]
+pub struct NoDropEmpty<T = ()>(
+    #[deref]
+    #[deref_mut]
+    #[as_mut]
+    #[as_ref]
+    T,
+    #[cfg_attr(feature = "serde", serde(skip, default = "Origin::caller"))] Origin,
+);
 
 impl<T> NoDropEmpty<T> {
     /// Creates a new wrapper around `value`.
-    pub fn wrap(value: T) -> Self {
-        Self(value)
+    ///
+    /// This is a `const fn`, so guards may be created in `const` contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// const ANSWER: NoDrop<i32> = NoDrop::wrap(42);
+    /// assert_eq!(ANSWER.consume(), 42);
+    /// ```
+    #[track_caller]
+    pub const fn wrap(value: T) -> Self {
+        Self(value, Origin::caller())
+    }
+
+    /// Creates a new wrapper around `value`, reporting `origin` as its creation location.
+    #[cfg(feature = "alloc")]
+    pub(crate) const fn from_parts(value: T, origin: Origin) -> Self {
+        Self(value, origin)
+    }
+
+    /// Consumes the wrapper, returning the inner `T` and its creation location.
+    pub(crate) fn into_parts(self) -> (T, Origin) {
+        let origin = self.1;
+        (self.consume(), origin)
+    }
+
+    /// Returns a reference to the inner `T`, without going through [`Deref`](core::ops::Deref).
+    ///
+    /// Unlike auto-deref, this is never shadowed by a method of `T` with the same name. The
+    /// wrapper stays armed, [`Self::consume`] is the only way to take the value out.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(42);
+    /// assert_eq!(*wrapper.inner(), 42);
+    /// wrapper.forget();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the inner `T`, without going through
+    /// [`DerefMut`](core::ops::DerefMut).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let mut wrapper = NoDrop::wrap(41);
+    /// *wrapper.inner_mut() += 1;
+    /// assert_eq!(wrapper.consume(), 42);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.0
     }
 
     /// Consumes the wrapper and returns the inner `T`.
@@ -33,48 +123,533 @@ impl<T> NoDropEmpty<T> {
     /// use no_drop::rls::NoDrop;
     ///
     /// let wrapper = NoDrop::wrap(42);
-    /// assert_eq!(wrapper.unwrap(), 42);
+    /// assert_eq!(wrapper.consume(), 42);
     /// ```
     #[inline]
     #[must_use]
-    pub fn unwrap(self) -> T {
+    pub fn consume(self) -> T {
         let this = ManuallyDrop::new(self);
         // SAFETY: `T` is moved out of the wrapper exactly once, then this type is dropped.
         // No uninitialized access can occur.
-        unsafe { std::ptr::read(&raw const this.0) }
+        unsafe { core::ptr::read(&raw const this.0) }
+    }
+
+    /// Consumes the wrapper and returns the inner `T`.
+    ///
+    /// This is the same as [`Self::consume`], under the conventional name for unwrapping a wrapper.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper: NoDrop<i32> = 42.into();
+    /// assert_eq!(wrapper.into_inner(), 42);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.consume()
+    }
+
+    /// Consumes the wrapper and returns the inner `T`, noting the `context` it was consumed in.
+    ///
+    /// This is infallible, like [`Self::consume`]. With the `tracing` feature, a `tracing` debug
+    /// event is emitted with `context` and the location the wrapper was created at.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(42);
+    /// assert_eq!(wrapper.consume_expect("answer sent to client"), 42);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn consume_expect(self, context: &str) -> T {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("{context} (created at {})", self.1);
+        #[cfg(not(feature = "tracing"))]
+        let _ = context;
+        self.consume()
+    }
+
+    /// Consumes the wrapper, passing the inner `T` to `f` and returning its result.
+    ///
+    /// The guard is disarmed before `f` is called, so if `f` [`panic!`]s the value is dropped
+    /// normally as part of the unwind, exactly once, and the guard does not [`panic!`] again.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(vec![1, 2, 3]);
+    /// assert_eq!(wrapper.consume_with_guarded(|v| v.len()), 3);
+    /// ```
+    #[inline]
+    #[doc(alias = "consume_map")]
+    pub fn consume_with_guarded<R>(self, f: impl FnOnce(T) -> R) -> R {
+        f(self.consume())
+    }
+
+    /// Consumes the wrapper, passing the inner `T` to `f` and returning its result.
+    ///
+    /// An alias of [`Self::consume_with_guarded`], named to pair with [`Self::map`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(21);
+    /// assert_eq!(wrapper.consume_map(|n| n * 2), 42);
+    /// ```
+    #[inline]
+    pub fn consume_map<U>(self, f: impl FnOnce(T) -> U) -> U {
+        self.consume_with_guarded(f)
+    }
+
+    /// Consumes the wrapper, converting the inner `T` into a `U`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(42_i32);
+    /// assert_eq!(wrapper.consume_into::<i64>(), 42_i64);
+    /// ```
+    #[inline]
+    pub fn consume_into<U: From<T>>(self) -> U {
+        self.consume().into()
+    }
+
+    /// Consumes the wrapper, returning an iterator over the inner collection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(vec![1, 2, 3]);
+    /// assert_eq!(wrapper.consume_iter().sum::<i32>(), 6);
+    /// ```
+    #[inline]
+    pub fn consume_iter(self) -> T::IntoIter
+    where
+        T: IntoIterator,
+    {
+        self.consume().into_iter()
+    }
+
+    /// Consumes the wrapper, applying `f` to the inner `T` and re-wrapping the result.
+    ///
+    /// If `f` [`panic!`]s, the value is dropped normally as part of the unwind, and the guard does
+    /// not [`panic!`] again.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(21);
+    /// assert_eq!(wrapper.map(|n| n * 2).consume(), 42);
+    /// ```
+    #[inline]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> NoDropEmpty<U> {
+        let origin = self.1;
+        NoDropEmpty(f(self.consume()), origin)
+    }
+
+    /// Consumes the wrapper, passing the inner `T` to `next`, which produces the next guard.
+    ///
+    /// This allows the consumption obligation to flow through multiple stages of a pipeline. The
+    /// value is extracted with [`Self::consume`], so no intermediate guard is ever dropped armed,
+    /// and the final guard must still be consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap("42");
+    /// let parsed = wrapper.then(|s| NoDrop::wrap(s.parse::<i32>().unwrap()));
+    /// assert_eq!(parsed.consume(), 42);
+    /// ```
+    #[inline]
+    #[doc(alias = "and_then")]
+    pub fn then<U>(self, next: impl FnOnce(T) -> NoDropEmpty<U>) -> NoDropEmpty<U> {
+        next(self.consume())
+    }
+
+    /// Consumes the wrapper, passing the inner `T` to a fallible `finalize`.
+    ///
+    /// If `finalize` fails, it must hand back the value, which is re-wrapped in a new armed guard
+    /// and returned alongside the error, so the consumption obligation is not lost.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error produced by `finalize`, paired with the re-wrapped value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(-1);
+    /// let (err, wrapper) = wrapper
+    ///     .consume_try(|n| if n > 0 { Ok(()) } else { Err(("not positive", n)) })
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(err, "not positive");
+    /// assert_eq!(wrapper.consume(), -1);
+    /// ```
+    pub fn consume_try<R, E>(self, finalize: impl FnOnce(T) -> Result<R, (E, T)>) -> Result<R, (E, Self)> {
+        let origin = self.1;
+        finalize(self.consume()).map_err(|(err, value)| (err, Self(value, origin)))
+    }
+
+    /// Returns the wrapper holding the greater value, [`Self::forget`]ting the other.
+    ///
+    /// Dropping the discarded guard would [`panic!`], so comparing guards and keeping one should go
+    /// through this rather than [`Ord::max`]. If the values are equal, `other` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let larger = NoDrop::wrap(1).max_consume(NoDrop::wrap(2));
+    /// assert_eq!(larger.consume(), 2);
+    /// ```
+    pub fn max_consume(self, other: Self) -> Self
+    where
+        T: Ord,
+    {
+        match self.0.cmp(&other.0) {
+            Ordering::Greater => {
+                other.forget();
+                self
+            }
+            Ordering::Less | Ordering::Equal => {
+                self.forget();
+                other
+            }
+        }
+    }
+
+    /// Returns the wrapper holding the lesser value, [`Self::forget`]ting the other.
+    ///
+    /// Dropping the discarded guard would [`panic!`], so comparing guards and keeping one should go
+    /// through this rather than [`Ord::min`]. If the values are equal, `self` is returned.
+    pub fn min_consume(self, other: Self) -> Self
+    where
+        T: Ord,
+    {
+        match self.0.cmp(&other.0) {
+            Ordering::Greater => {
+                self.forget();
+                other
+            }
+            Ordering::Less | Ordering::Equal => {
+                other.forget();
+                self
+            }
+        }
+    }
+
+    /// Consumes the wrapper, moving the inner `T` into a [`NoDropMsg`] with a custom [`panic!`]
+    /// `msg`.
+    ///
+    /// The value is never dropped or consumed in between, so the obligation carries over to the
+    /// new guard, which reports the original creation location.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,should_panic
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(42).with_msg("answer was not processed");
+    /// drop(wrapper); // panic: "answer was not processed"
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn with_msg<'msg, M: Into<Cow<'msg, str>>>(self, msg: M) -> NoDropMsg<'msg, T> {
+        let origin = self.1;
+        NoDropMsg::from_parts(self.consume(), Message::from(msg.into()), origin)
+    }
+
+    /// Consumes the wrapper and returns the inner `T`.
+    #[deprecated(since = "0.3.0", note = "renamed to `consume`")]
+    #[inline]
+    #[must_use]
+    pub fn unwrap(self) -> T {
+        self.consume()
+    }
+
+    /// Replaces the inner `T` with `value`, returning the old value, while the wrapper stays armed.
+    ///
+    /// The returned value is a plain owned `T`, no longer guarded. This is an associated function,
+    /// called as `NoDrop::replace(&mut wrapper, value)`, so it does not shadow any `replace`
+    /// method of `T`, such as [`Option::replace`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let mut wrapper = NoDrop::wrap(vec![1, 2]);
+    /// assert_eq!(NoDrop::replace(&mut wrapper, vec![3]), vec![1, 2]);
+    /// assert_eq!(wrapper.consume(), vec![3]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn replace(this: &mut Self, value: T) -> T {
+        core::mem::replace(&mut this.0, value)
+    }
+
+    /// Swaps the inner values of two wrappers. Each keeps its own creation location, and both stay
+    /// armed.
+    ///
+    /// This is an associated function, called as `NoDrop::swap(&mut a, &mut b)`, so it does not
+    /// shadow any `swap` method of `T`, such as [`slice::swap`].
+    #[inline]
+    pub fn swap(this: &mut Self, other: &mut Self) {
+        core::mem::swap(&mut this.0, &mut other.0);
     }
 
     /// Forgets this guard, safely dropping it.
     #[inline]
     pub fn forget(self) {
-        let _ = ManuallyDrop::new(self);
+        drop(self.consume());
+    }
+
+    /// Forgets this guard like [`Self::forget`], noting it on stderr in debug builds.
+    ///
+    /// The notice includes where the value was created, leaving a breadcrumb when a real leak is
+    /// papered over by forgetting the guard. It never [`panic!`]s, and without `std`, or in release
+    /// builds, this is identical to [`Self::forget`].
+    #[inline]
+    pub fn debug_forget(self) {
+        #[cfg(all(feature = "std", debug_assertions))]
+        super::notice_forgotten(&DEFAULT_DROP_PANIC_MSG, self.1);
+        self.forget();
+    }
+}
+
+/// Pin projection, for guarding `!Unpin` values.
+///
+/// The wrapper never moves its value while it is pinned, so the value is structurally pinned. Only
+/// [`Self::consume`] moves it, which requires an owned, and so unpinned, wrapper. A pinned wrapper
+/// can only be consumed via [`Self::consume_pinned`] if `T: Unpin`, or the `unsafe`
+/// [`Self::consume_pinned_unchecked`] otherwise.
+impl<T> NoDropEmpty<T> {
+    /// Projects a pinned reference to the wrapper to a pinned reference to its value.
+    pub fn as_pin_ref(self: Pin<&Self>) -> Pin<&T> {
+        // SAFETY: The value is structurally pinned, see above.
+        unsafe { self.map_unchecked(|this| &this.0) }
+    }
+
+    /// Projects a pinned mutable reference to the wrapper to a pinned mutable reference to its
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::marker::PhantomPinned;
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let mut wrapper = Box::pin(NoDrop::wrap(PhantomPinned));
+    /// let _value: core::pin::Pin<&mut PhantomPinned> = wrapper.as_mut().as_pin_mut();
+    /// // SAFETY: `PhantomPinned` does not rely on its address.
+    /// let _value = unsafe { wrapper.consume_pinned_unchecked() };
+    /// ```
+    pub fn as_pin_mut(self: Pin<&mut Self>) -> Pin<&mut T> {
+        // SAFETY: The value is structurally pinned, see above.
+        unsafe { self.map_unchecked_mut(|this| &mut this.0) }
+    }
+
+    /// Consumes a pinned, boxed, wrapper and returns the inner `T`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn consume_pinned(self: Pin<Box<Self>>) -> T
+    where
+        T: Unpin,
+    {
+        Pin::into_inner(self).consume()
+    }
+
+    /// Consumes a pinned, boxed, wrapper and returns the inner `T`, moving it out of the pin.
+    ///
+    /// # Safety
+    ///
+    /// Moving the value must not violate its pinning guarantees. That is, the value must not rely
+    /// on its address remaining stable, such as being self-referential.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub unsafe fn consume_pinned_unchecked(self: Pin<Box<Self>>) -> T {
+        // SAFETY: The caller guarantees the value may be moved.
+        unsafe { Pin::into_inner_unchecked(self) }.consume()
+    }
+}
+
+impl<I: Iterator> NoDropEmpty<I> {
+    /// Borrows the guarded iterator, allowing it to be partially driven while leaving the guard
+    /// armed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let mut wrapper = NoDrop::wrap(1..=5);
+    /// let head: Vec<_> = wrapper.by_ref().take(2).collect();
+    /// let rest: Vec<_> = wrapper.consume().collect();
+    ///
+    /// assert_eq!(head, vec![1, 2]);
+    /// assert_eq!(rest, vec![3, 4, 5]);
+    /// ```
+    pub fn by_ref(&mut self) -> &mut I {
+        &mut self.0
+    }
+}
+
+/// Guarded, possibly uninitialized, buffers, such as those filled in over FFI.
+///
+/// The consumption obligation is attached to the buffer as soon as it is wrapped, and carries over
+/// to the initialized value through [`Self::assume_init`], along with the original creation
+/// location.
+impl<T> NoDropEmpty<MaybeUninit<T>> {
+    /// Creates a new guard around an uninitialized `T`.
+    #[track_caller]
+    pub const fn uninit() -> Self {
+        Self::wrap(MaybeUninit::uninit())
+    }
+
+    /// Returns a raw pointer to the guarded buffer, for it to be initialized through.
+    ///
+    /// Reading from the pointer before the buffer is initialized is undefined behavior. The
+    /// pointer is only valid while the guard is neither moved nor dropped.
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
+        self.0.as_mut_ptr()
+    }
+
+    /// Transitions the guard from an uninitialized buffer to the initialized `T`, keeping it
+    /// armed.
+    ///
+    /// # Safety
+    ///
+    /// The buffer must be fully initialized to a valid `T`, for example by writing through
+    /// [`Self::as_mut_ptr`], exactly as required by [`MaybeUninit::assume_init`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let mut buffer = NoDrop::<core::mem::MaybeUninit<u32>>::uninit();
+    /// // Stands in for passing the pointer to a C function that fills it.
+    /// unsafe { buffer.as_mut_ptr().write(42) };
+    /// // SAFETY: The buffer was initialized above.
+    /// let value = unsafe { buffer.assume_init() };
+    /// assert_eq!(value.consume(), 42);
+    /// ```
+    pub unsafe fn assume_init(self) -> NoDropEmpty<T> {
+        let origin = self.1;
+        // SAFETY: The caller guarantees the buffer is initialized.
+        NoDropEmpty(unsafe { self.consume().assume_init() }, origin)
     }
 }
 
 impl NoDropEmpty<()> {
     /// Creates a new empty [`NoDropEmpty`] guard.
+    #[track_caller]
     pub const fn new() -> Self {
-        Self(())
+        Self((), Origin::caller())
     }
 }
 
 impl Default for NoDropEmpty<()> {
+    #[track_caller]
     fn default() -> Self {
-        Self(())
+        Self::new()
     }
 }
 
-impl Clone for NoDropEmpty<()> {
+/// Cloning an armed guard creates a second armed guard, so both the original and the clone must
+/// be consumed. The clone reports the original's creation location.
+impl<T: Clone> Clone for NoDropEmpty<T> {
     fn clone(&self) -> Self {
-        Self::new()
+        Self(self.0.clone(), self.1)
+    }
+}
+
+/// Wraps `value` in a [`NoDropEmpty`] for the duration of `body`, then consumes it, returning the
+/// value alongside the result of `body`.
+///
+/// If `body` [`panic!`]s, the value is dropped as part of the unwind, without a second [`panic!`].
+///
+/// # Examples
+///
+/// ```rust
+/// use no_drop::rls::with_guard;
+///
+/// let (values, len) = with_guard(vec![1, 2], |values| {
+///     values.push(3);
+///     values.len()
+/// });
+///
+/// assert_eq!(values, vec![1, 2, 3]);
+/// assert_eq!(len, 3);
+/// ```
+#[track_caller]
+pub fn with_guard<T, R>(value: T, body: impl FnOnce(&mut T) -> R) -> (T, R) {
+    let mut wrapper = NoDropEmpty::wrap(value);
+    let result = body(&mut wrapper);
+    (wrapper.consume(), result)
+}
+
+impl<T> Borrow<T> for NoDropEmpty<T> {
+    fn borrow(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> BorrowMut<T> for NoDropEmpty<T> {
+    fn borrow_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T> From<T> for NoDropEmpty<T> {
+    #[track_caller]
+    fn from(value: T) -> Self {
+        Self::wrap(value)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for NoDropEmpty<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: fmt::Pointer> fmt::Pointer for NoDropEmpty<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
     }
 }
 
 impl<T> Drop for NoDropEmpty<T> {
-    /// [`panic!`]s.
+    /// [`panic!`]s, unless the thread is already panicking.
+    ///
+    /// Only reached if the value was not consumed, so this is kept out of line.
+    #[cold]
+    #[inline(never)]
     #[track_caller]
     fn drop(&mut self) {
-        panic!("{}", DEFAULT_DROP_PANIC_MSG);
+        panic_with(DEFAULT_DROP_PANIC_MSG, self.1);
     }
 }
 
@@ -85,12 +660,65 @@ mod tests {
     use crate::no_drop::test_macros::{test_clone, test_ctor, test_forget};
 
     #[test]
-    #[should_panic(expected = "Value was dropped without being unwrapped")]
+    fn forget_drops_value() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        NoDropEmpty::wrap(Rc::clone(&rc)).forget();
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn debug_forget_drops_value() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        NoDropEmpty::wrap(Rc::clone(&rc)).debug_forget();
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
     fn no_drop_empty_panics() {
         let wrapper = NoDropEmpty::wrap(42);
         drop(wrapper);
     }
 
+    #[test]
+    fn replace_returns_unguarded_old_value() {
+        let mut wrapper = NoDropEmpty::wrap(String::from("old"));
+        let old = NoDropEmpty::replace(&mut wrapper, String::from("new"));
+        drop(old);
+        assert_eq!(wrapper.consume(), "new");
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn replace_stays_armed() {
+        let mut wrapper = NoDropEmpty::wrap(1);
+        let _ = NoDropEmpty::replace(&mut wrapper, 2);
+        drop(wrapper);
+    }
+
+    #[test]
+    fn swap_exchanges_values() {
+        let mut a = NoDropEmpty::wrap(1);
+        let mut b = NoDropEmpty::wrap(2);
+        NoDropEmpty::swap(&mut a, &mut b);
+        assert_eq!((a.consume(), b.consume()), (2, 1));
+    }
+
+    #[test]
+    fn swap_and_replace_reach_inner_methods() {
+        let mut wrapper = NoDropEmpty::wrap(vec![1, 2, 3]);
+        wrapper.swap(0, 1);
+        assert_eq!(wrapper.consume(), vec![2, 1, 3]);
+
+        let mut wrapper = NoDropEmpty::wrap(Some(1));
+        assert_eq!(wrapper.replace(2), Some(1));
+        assert_eq!(wrapper.consume(), Some(2));
+    }
+
     test_ctor!(no_drop_empty_wrap, NoDropEmpty::wrap, (42), 42);
     test_ctor!(into_no_drop_dbg_trait, IntoNoDropDbg::no_drop, (42), 42);
     test_ctor!(into_no_drop_rls_trait, IntoNoDropRls::no_drop, (42), 42);
@@ -100,4 +728,390 @@ mod tests {
     test_clone!(no_drop_empty_clone, NoDropEmpty, NoDropEmpty::new, ());
 
     test_forget!(no_drop_empty_forget, NoDropEmpty::new, ());
+
+    #[test]
+    fn consume_with_guarded_returns_result() {
+        let wrapper = NoDropEmpty::wrap(21);
+        assert_eq!(wrapper.consume_with_guarded(|value| value * 2), 42);
+    }
+
+    #[test]
+    fn consume_with_guarded_drops_value_once_on_panic() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+        struct Payload;
+
+        impl Drop for Payload {
+            fn drop(&mut self) {
+                DROPS.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let wrapper = NoDropEmpty::wrap(Payload);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            wrapper.consume_with_guarded(|_payload| panic!("finalizer failed"));
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn into_inner_round_trips() {
+        let wrapper: NoDropEmpty<_> = String::from("value").into();
+        assert_eq!(wrapper.into_inner(), "value");
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn from_value_is_armed() {
+        let wrapper = NoDropEmpty::from(42);
+        drop(wrapper);
+    }
+
+    #[test]
+    fn consume_iter_sums_vec() {
+        let wrapper: NoDropEmpty<Vec<i32>> = NoDropEmpty::wrap(vec![1, 2, 3]);
+        assert_eq!(wrapper.consume_iter().sum::<i32>(), 6);
+    }
+
+    #[test]
+    fn max_consume_keeps_larger() {
+        assert_eq!(NoDropEmpty::wrap(1).max_consume(NoDropEmpty::wrap(2)).consume(), 2);
+        assert_eq!(NoDropEmpty::wrap(2).max_consume(NoDropEmpty::wrap(1)).consume(), 2);
+    }
+
+    #[test]
+    fn min_consume_keeps_smaller() {
+        assert_eq!(NoDropEmpty::wrap(1).min_consume(NoDropEmpty::wrap(2)).consume(), 1);
+        assert_eq!(NoDropEmpty::wrap(2).min_consume(NoDropEmpty::wrap(1)).consume(), 1);
+    }
+
+    #[test]
+    fn max_consume_ties_match_ord() {
+        use std::cmp::Ordering;
+
+        #[derive(Debug, PartialEq, Eq)]
+        struct Keyed(i32, &'static str);
+
+        impl PartialOrd for Keyed {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Keyed {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        let max = NoDropEmpty::wrap(Keyed(1, "self")).max_consume(NoDropEmpty::wrap(Keyed(1, "other")));
+        assert_eq!(max.consume().1, "other");
+        let min = NoDropEmpty::wrap(Keyed(1, "self")).min_consume(NoDropEmpty::wrap(Keyed(1, "other")));
+        assert_eq!(min.consume().1, "self");
+    }
+
+    #[test]
+    fn map_transforms_value() {
+        let wrapper = NoDropEmpty::wrap(21).map(|n| n * 2);
+        assert_eq!(wrapper.consume(), 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn map_stays_armed() {
+        let wrapper = NoDropEmpty::wrap(21).map(|n| n * 2);
+        drop(wrapper);
+    }
+
+    #[test]
+    fn map_panicking_does_not_panic_again() {
+        let wrapper = NoDropEmpty::wrap(21);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _ = wrapper.map(|_| -> i32 { panic!("map failed") });
+        }));
+
+        assert_eq!(result.unwrap_err().downcast_ref::<&str>(), Some(&"map failed"));
+    }
+
+    #[test]
+    fn then_chains_stages() {
+        let wrapper = NoDropEmpty::wrap("21");
+        let validated = wrapper
+            .then(|s| NoDropEmpty::wrap(s.parse::<i32>()))
+            .then(|parsed| NoDropEmpty::wrap(parsed.map(|n| n * 2)));
+        assert_eq!(validated.consume(), Ok(42));
+    }
+
+    #[test]
+    fn consume_try_success() {
+        let wrapper = NoDropEmpty::wrap(42);
+        let result: Result<i32, (&str, _)> = wrapper.consume_try(Ok);
+        assert_eq!(result.ok(), Some(42));
+    }
+
+    #[test]
+    fn consume_try_error_rewraps() {
+        let wrapper = NoDropEmpty::wrap(42);
+        let (err, wrapper) = wrapper.consume_try(|value| Err::<(), _>(("failed", value))).unwrap_err();
+        assert_eq!(err, "failed");
+        assert_eq!(wrapper.consume(), 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn consume_try_error_stays_armed() {
+        let wrapper = NoDropEmpty::wrap(42);
+        let (_, wrapper) = wrapper.consume_try(|value| Err::<(), _>(((), value))).unwrap_err();
+        drop(wrapper);
+    }
+
+    #[test]
+    fn by_ref_partial_then_collect() {
+        let mut wrapper = NoDropEmpty::wrap(vec![1, 2, 3, 4, 5].into_iter());
+        assert_eq!(wrapper.by_ref().take(3).collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(wrapper.consume().collect::<Vec<_>>(), vec![4, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn by_ref_items_remaining_panics() {
+        let mut wrapper = NoDropEmpty::wrap(1..=5);
+        wrapper.by_ref().take(3).for_each(drop);
+        drop(wrapper);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn then_final_guard_armed() {
+        let wrapper = NoDropEmpty::wrap(1).then(|n| NoDropEmpty::wrap(n + 1));
+        drop(wrapper);
+    }
+
+    #[test]
+    fn uninit_filled_through_pointer() {
+        let mut buffer = NoDropEmpty::<MaybeUninit<[u8; 4]>>::uninit();
+        // SAFETY: The pointer is valid for writes of the whole array.
+        unsafe { buffer.as_mut_ptr().write([1, 2, 3, 4]) };
+        // SAFETY: The buffer was initialized above.
+        let value = unsafe { buffer.assume_init() };
+        assert_eq!(value.consume(), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn uninit_assumed_init_still_armed() {
+        let mut buffer = NoDropEmpty::<MaybeUninit<u32>>::uninit();
+        // SAFETY: The pointer is valid for writes.
+        unsafe { buffer.as_mut_ptr().write(42) };
+        // SAFETY: The buffer was initialized above.
+        let value = unsafe { buffer.assume_init() };
+        drop(value);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn uninit_unfilled_still_armed() {
+        drop(NoDropEmpty::<MaybeUninit<u32>>::uninit());
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn then_two_stages_final_guard_armed() {
+        let wrapper = NoDropEmpty::wrap(1).then(|n| NoDropEmpty::wrap(n + 1)).then(|n| NoDropEmpty::wrap(n * 2));
+        assert_eq!(*wrapper, 4);
+        drop(wrapper);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let original = NoDropEmpty::wrap(vec![1, 2, 3]);
+        let json = serde_json::to_string(&original).unwrap();
+        assert_eq!(json, "[1,2,3]");
+        original.forget();
+
+        let wrapper: NoDropEmpty<Vec<i32>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(wrapper.consume(), vec![1, 2, 3]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn serde_deserialized_panics() {
+        let wrapper: NoDropEmpty<i32> = serde_json::from_str("42").unwrap();
+        drop(wrapper);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn drop_during_unwind_does_not_abort() {
+        let result = std::panic::catch_unwind(|| {
+            let _wrapper = NoDropEmpty::wrap(42);
+            panic!("first panic");
+        });
+
+        let payload = result.unwrap_err();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"first panic"));
+    }
+
+    #[test]
+    fn with_guard_returns_value_and_result() {
+        let (value, result) = with_guard(41, |value| {
+            *value += 1;
+            "done"
+        });
+
+        assert_eq!(value, 42);
+        assert_eq!(result, "done");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn with_guard_panicking_body_does_not_double_panic() {
+        let result = std::panic::catch_unwind(|| with_guard(42, |_| -> () { panic!("body failed") }));
+
+        let payload = result.unwrap_err();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"body failed"));
+    }
+
+    #[test]
+    fn drop_panic_reports_origin() {
+        let (wrapper, line) = (NoDropEmpty::wrap(42), line!());
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| drop(wrapper)));
+
+        let payload = result.unwrap_err();
+        let msg = payload.downcast_ref::<String>().unwrap();
+        assert!(msg.starts_with(DEFAULT_DROP_PANIC_MSG), "{msg}");
+        assert!(msg.contains(&format!("(created at {}:{line}:", file!())), "{msg}");
+    }
+
+    #[test]
+    fn origin_ignored_by_comparisons() {
+        let a = NoDropEmpty::wrap(42);
+        let b = NoDropEmpty::wrap(42);
+        assert_eq!(a, b);
+        a.forget();
+        b.forget();
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn lookup_by_borrowed_value() {
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+        set.insert(NoDropEmpty::wrap(String::from("key")));
+
+        assert!(set.contains(&String::from("key")));
+        assert!(!set.contains(&String::from("other")));
+        set.into_iter().for_each(NoDropEmpty::forget);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn with_msg_keeps_value() {
+        assert_eq!(NoDropEmpty::wrap(42).with_msg("message").consume(), 42);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn with_msg_drop_panics_with_msg() {
+        let (wrapper, line) = (NoDropEmpty::wrap(42), line!());
+        let wrapper = wrapper.with_msg("attached message");
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| drop(wrapper)));
+
+        let payload = result.unwrap_err();
+        let msg = payload.downcast_ref::<String>().unwrap();
+        assert!(msg.starts_with("attached message"), "{msg}");
+        assert!(msg.contains(&format!("(created at {}:{line}:", file!())), "{msg}");
+    }
+
+    #[test]
+    fn consume_expect_returns_value() {
+        assert_eq!(NoDropEmpty::wrap(42).consume_expect("context"), 42);
+    }
+
+    #[test]
+    fn consume_map_and_into() {
+        assert_eq!(NoDropEmpty::wrap(21).consume_map(|n| n * 2), 42);
+        assert_eq!(NoDropEmpty::wrap(42_i32).consume_into::<i64>(), 42_i64);
+    }
+
+    #[test]
+    fn inner_accessors() {
+        let mut wrapper = NoDropEmpty::wrap(vec![1, 2]);
+        wrapper.inner_mut().push(3);
+        assert_eq!(wrapper.inner(), &[1, 2, 3]);
+        assert_eq!(wrapper.consume(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn display_forwards_to_value() {
+        let wrapper = NoDropEmpty::wrap(42);
+        assert_eq!(format!("{wrapper}"), "42");
+        assert_eq!(format!("{wrapper:>4}"), "  42");
+        wrapper.forget();
+    }
+
+    #[test]
+    fn pointer_forwards_to_value() {
+        let boxed = Box::new(42);
+        let expected = format!("{boxed:p}");
+        let wrapper = NoDropEmpty::wrap(boxed);
+        assert_eq!(format!("{wrapper:p}"), expected);
+        wrapper.forget();
+    }
+
+    #[test]
+    fn clone_value() {
+        let wrapper = NoDropEmpty::wrap(42);
+        let clone = wrapper.clone();
+        assert_eq!(wrapper.consume(), clone.consume());
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn clone_is_armed() {
+        let wrapper = NoDropEmpty::wrap(42);
+        let clone = wrapper.clone();
+        assert_eq!(wrapper.consume(), 42);
+        drop(clone);
+    }
+
+    #[test]
+    fn wrap_in_const() {
+        const WRAPPER: NoDropEmpty<i32> = NoDropEmpty::wrap(42);
+        assert_eq!(WRAPPER.consume(), 42);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn pin_projection() {
+        use core::marker::PhantomPinned;
+
+        let mut wrapper = Box::pin(NoDropEmpty::wrap(PhantomPinned));
+        let _value: Pin<&mut PhantomPinned> = wrapper.as_mut().as_pin_mut();
+        let _value: Pin<&PhantomPinned> = wrapper.as_ref().as_pin_ref();
+        // SAFETY: `PhantomPinned` does not rely on its address.
+        let _value = unsafe { wrapper.consume_pinned_unchecked() };
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn consume_pinned_unpin() {
+        let mut wrapper = Box::pin(NoDropEmpty::wrap(41));
+        *wrapper.as_mut().as_pin_mut() += 1;
+        assert_eq!(wrapper.consume_pinned(), 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn pinned_drop_panics() {
+        let wrapper = Box::pin(NoDropEmpty::wrap(core::marker::PhantomPinned));
+        drop(wrapper);
+    }
 }
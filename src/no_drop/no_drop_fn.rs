@@ -0,0 +1,55 @@
+use alloc::boxed::Box;
+
+use super::NoDropEmpty;
+
+/// Methods for guarded boxed callbacks, that must be run before being dropped.
+impl<'f, A, R> NoDropEmpty<Box<dyn FnMut(A) -> R + 'f>> {
+    /// Calls the guarded callback with `arg`, leaving the guard armed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let mut total = 0;
+    /// let mut callback = NoDrop::wrap(Box::new(|x: i32| total += x) as Box<dyn FnMut(i32)>);
+    /// callback.call(1);
+    /// callback.run_final(2);
+    /// assert_eq!(total, 3);
+    /// ```
+    pub fn call(&mut self, arg: A) -> R {
+        (**self)(arg)
+    }
+
+    /// Consumes the guard, calling the callback one final time with `arg`.
+    pub fn run_final(self, arg: A) -> R {
+        let mut callback = self.consume();
+        callback(arg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn call_then_run_final() {
+        let mut calls = Vec::new();
+        let mut wrapper: NoDropEmpty<Box<dyn FnMut(i32) -> usize>> = NoDropEmpty::wrap(Box::new(|x| {
+            calls.push(x);
+            calls.len()
+        }));
+
+        assert_eq!(wrapper.call(1), 1);
+        assert_eq!(wrapper.call(2), 2);
+        assert_eq!(wrapper.run_final(3), 3);
+        assert_eq!(calls, vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn dropped_without_running_panics() {
+        let wrapper: NoDropEmpty<Box<dyn FnMut(())>> = NoDropEmpty::wrap(Box::new(|()| {}));
+        drop(wrapper);
+    }
+}
@@ -0,0 +1,117 @@
+use super::leak::panic_with;
+use super::Origin;
+
+pub const UNEXHAUSTED_ITER_PANIC_MSG: &str = "iterator dropped before being exhausted";
+
+/// An iterator adapter that [`panic!`]s if dropped before its iterator is exhausted.
+///
+/// This enforces must-drain invariants, such as a parser that must reach the end of its input. The
+/// iterator is exhausted once [`Iterator::next`] returns [`None`]. Dropping the adapter before then
+/// [`panic!`]s with [`UNEXHAUSTED_ITER_PANIC_MSG`], and the location the adapter was created at,
+/// unless it is deliberately [`Self::abandon`]ed.
+///
+/// # Examples
+///
+/// ```rust
+/// use no_drop::rls::NoDropIter;
+///
+/// let mut tokens = NoDropIter::wrap("a b c".split(' '));
+/// assert_eq!(tokens.next(), Some("a"));
+/// let rest: Vec<_> = tokens.by_ref().collect();
+/// assert_eq!(rest, vec!["b", "c"]);
+/// assert!(tokens.is_exhausted());
+/// ```
+#[derive(Debug)]
+#[must_use = "the iterator must be exhausted, or it will panic when dropped"]
+pub struct NoDropIter<I: Iterator> {
+    iter: I,
+    exhausted: bool,
+    origin: Origin,
+}
+
+impl<I: Iterator> NoDropIter<I> {
+    /// Creates a new adapter around `iter`.
+    #[track_caller]
+    pub const fn wrap(iter: I) -> Self {
+        Self { iter, exhausted: false, origin: Origin::caller() }
+    }
+
+    /// Returns whether the iterator has returned [`None`].
+    #[inline]
+    #[must_use]
+    pub const fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+
+    /// Drops the adapter without exhausting the iterator, and without [`panic!`]ing.
+    #[inline]
+    pub fn abandon(mut self) {
+        self.exhausted = true;
+    }
+}
+
+impl<I: Iterator> Iterator for NoDropIter<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next();
+        self.exhausted |= item.is_none();
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: Iterator> Drop for NoDropIter<I> {
+    fn drop(&mut self) {
+        if !self.exhausted {
+            panic_with(UNEXHAUSTED_ITER_PANIC_MSG, self.origin);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exhausted_drop_is_fine() {
+        let mut iter = NoDropIter::wrap(1..=3);
+        assert_eq!(iter.by_ref().sum::<i32>(), 6);
+        assert!(iter.is_exhausted());
+        drop(iter);
+    }
+
+    #[test]
+    fn empty_iterator_exhausted_by_first_next() {
+        let mut iter = NoDropIter::wrap(core::iter::empty::<i32>());
+        assert!(!iter.is_exhausted());
+        assert_eq!(iter.next(), None);
+        assert!(iter.is_exhausted());
+    }
+
+    #[test]
+    #[should_panic(expected = "iterator dropped before being exhausted")]
+    fn drop_mid_iteration_panics() {
+        let mut iter = NoDropIter::wrap(1..=3);
+        assert_eq!(iter.next(), Some(1));
+        drop(iter);
+    }
+
+    #[test]
+    #[should_panic(expected = "iterator dropped before being exhausted")]
+    fn drop_after_last_item_panics() {
+        let mut iter = NoDropIter::wrap(1..=1);
+        assert_eq!(iter.next(), Some(1));
+        drop(iter);
+    }
+
+    #[test]
+    fn abandon_suppresses_panic() {
+        let mut iter = NoDropIter::wrap(1..=3);
+        assert_eq!(iter.next(), Some(1));
+        iter.abandon();
+    }
+}
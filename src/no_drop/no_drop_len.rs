@@ -0,0 +1,107 @@
+use alloc::collections::{BTreeMap, BTreeSet, VecDeque};
+use alloc::string::String;
+use alloc::vec::Vec;
+use sealed::sealed;
+
+use super::NoDropEmpty;
+
+/// Sealed trait for containers with a length.
+///
+/// This trait is sealed and cannot be implemented outside this crate.
+#[sealed]
+pub trait Lengthed {
+    /// Returns the number of elements in the container.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the container contains no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+macro_rules! impl_lengthed {
+    ($([$($generics:tt)*] $ty:ty),* $(,)?) => {
+        $(
+            #[sealed]
+            impl<$($generics)*> Lengthed for $ty {
+                fn len(&self) -> usize {
+                    <$ty>::len(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_lengthed!([T] Vec<T>, [] String, [T] VecDeque<T>, [K, V] BTreeMap<K, V>, [T] BTreeSet<T>);
+
+#[cfg(feature = "std")]
+impl_lengthed!(
+    [K, V, S] std::collections::HashMap<K, V, S>,
+    [T, S] std::collections::HashSet<T, S>,
+);
+
+/// Length queries for guarded containers.
+///
+/// All methods leave the guard armed.
+impl<T: Lengthed> NoDropEmpty<T> {
+    /// Returns the number of elements in the guarded container.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(String::from("abc"));
+    /// assert_eq!(wrapper.len(), 3);
+    /// assert!(!wrapper.is_empty());
+    /// wrapper.forget();
+    /// ```
+    pub fn len(&self) -> usize {
+        T::len(self)
+    }
+
+    /// Returns `true` if the guarded container contains no elements.
+    pub fn is_empty(&self) -> bool {
+        T::is_empty(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vec_len() {
+        let wrapper = NoDropEmpty::wrap(vec![1, 2, 3]);
+        assert_eq!(wrapper.len(), 3);
+        assert!(!wrapper.is_empty());
+        assert_eq!(wrapper.consume(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn string_len() {
+        let wrapper = NoDropEmpty::wrap(String::new());
+        assert_eq!(wrapper.len(), 0);
+        assert!(wrapper.is_empty());
+        assert_eq!(wrapper.consume(), "");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_map_len() {
+        use std::collections::HashMap;
+
+        let wrapper = NoDropEmpty::wrap(HashMap::from([("a", 1), ("b", 2)]));
+        assert_eq!(wrapper.len(), 2);
+        assert!(!wrapper.is_empty());
+        assert_eq!(wrapper.consume(), HashMap::from([("a", 1), ("b", 2)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn len_stays_armed() {
+        let wrapper = NoDropEmpty::wrap(vec![1, 2, 3]);
+        assert_eq!(wrapper.len(), 3);
+        drop(wrapper);
+    }
+}
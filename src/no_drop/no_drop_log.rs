@@ -0,0 +1,210 @@
+use alloc::borrow::Cow;
+use core::mem::ManuallyDrop;
+
+use super::{Origin, DEFAULT_DROP_PANIC_MSG};
+
+/// Emits a [`tracing::error!`] event with `msg` and the `origin` of the dropped value.
+#[cold]
+#[inline(never)]
+fn log_with(msg: &str, origin: Origin) {
+    #[cfg(feature = "metrics")]
+    crate::metrics::record_armed_drop();
+
+    tracing::error!("{msg} (created at {origin})");
+}
+
+/// A wrapper around a `T` value that emits a [`tracing`] error event if dropped without being
+/// [`Self::consume`]d or [`Self::forget`]ten.
+///
+/// Unlike [`NoDropEmpty`](super::NoDropEmpty), dropping this type does not [`panic!`]. The value is
+/// dropped normally after the event is emitted.
+#[derive(
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Debug,
+    derive_more::Deref,
+    derive_more::DerefMut,
+    derive_more::AsMut,
+    derive_more::AsRef,
+)]
+#[must_use = "the value must be consumed, or it will be logged as leaked when dropped"]
+pub struct NoDropLogEmpty<T = ()>(
+    #[deref]
+    #[deref_mut]
+    #[as_mut]
+    #[as_ref]
+    T,
+    Origin,
+);
+
+impl<T> NoDropLogEmpty<T> {
+    /// Creates a new wrapper around `value`.
+    #[track_caller]
+    pub const fn wrap(value: T) -> Self {
+        Self(value, Origin::caller())
+    }
+
+    /// Consumes the wrapper and returns the inner `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::log_only::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(42);
+    /// assert_eq!(wrapper.consume(), 42);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn consume(self) -> T {
+        let this = ManuallyDrop::new(self);
+        // SAFETY: `T` is moved out of the wrapper exactly once, then this type is dropped.
+        // No uninitialized access can occur.
+        unsafe { core::ptr::read(&raw const this.0) }
+    }
+
+    /// Forgets this guard, safely dropping it.
+    #[inline]
+    pub fn forget(self) {
+        drop(self.consume());
+    }
+}
+
+impl NoDropLogEmpty<()> {
+    /// Creates a new empty [`NoDropLogEmpty`] guard.
+    #[track_caller]
+    pub const fn new() -> Self {
+        Self((), Origin::caller())
+    }
+}
+
+impl Default for NoDropLogEmpty<()> {
+    #[track_caller]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for NoDropLogEmpty<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1)
+    }
+}
+
+impl<T> Drop for NoDropLogEmpty<T> {
+    /// Emits [`DEFAULT_DROP_PANIC_MSG`] as a [`tracing`] error event.
+    fn drop(&mut self) {
+        log_with(DEFAULT_DROP_PANIC_MSG, self.1);
+    }
+}
+
+/// A wrapper around a `T` `value` with a custom `msg` that emits a [`tracing`] error event if
+/// dropped without being [`Self::consume`]d or [`Self::forget`]ten.
+///
+/// Unlike [`NoDropMsg`](super::NoDropMsg), dropping this type does not [`panic!`]. The value is
+/// dropped normally after the event is emitted.
+#[derive(
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Debug,
+    derive_more::Deref,
+    derive_more::DerefMut,
+    derive_more::AsMut,
+    derive_more::AsRef,
+)]
+#[must_use = "the value must be consumed, or it will be logged as leaked when dropped"]
+pub struct NoDropLogMsg<'msg, T = ()> {
+    #[deref]
+    #[deref_mut]
+    #[as_mut]
+    #[as_ref]
+    value: T,
+    msg: Cow<'msg, str>,
+    origin: Origin,
+}
+
+impl<'msg, T> NoDropLogMsg<'msg, T> {
+    /// Creates a new wrapper around `value` with a custom `msg`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::log_only::NoDropMsg;
+    ///
+    /// let wrapper = NoDropMsg::wrap(42, "forgot to flush this buffer");
+    /// assert_eq!(wrapper.consume(), 42);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn wrap<M: Into<Cow<'msg, str>>>(value: T, msg: M) -> Self {
+        Self { value, msg: msg.into(), origin: Origin::caller() }
+    }
+
+    /// Consumes the wrapper and returns the inner `T`.
+    #[inline]
+    #[must_use]
+    pub fn consume(self) -> T {
+        let this = ManuallyDrop::new(self);
+        // SAFETY: `T` and `msg` are each moved out of the wrapper exactly once, then this is
+        // dropped. No uninitialized access can occur.
+        let (value, _msg) = unsafe { (core::ptr::read(&raw const this.value), core::ptr::read(&raw const this.msg)) };
+        value
+    }
+
+    /// Forgets this guard, safely dropping it.
+    #[inline]
+    pub fn forget(self) {
+        drop(self.consume());
+    }
+}
+
+impl<'msg> NoDropLogMsg<'msg, ()> {
+    /// Creates a new empty [`NoDropLogMsg`] guard with a custom `msg`.
+    #[track_caller]
+    pub fn guard<M: Into<Cow<'msg, str>>>(msg: M) -> Self {
+        Self { value: (), msg: msg.into(), origin: Origin::caller() }
+    }
+}
+
+impl<T: Clone> Clone for NoDropLogMsg<'_, T> {
+    fn clone(&self) -> Self {
+        Self { value: self.value.clone(), msg: self.msg.clone(), origin: self.origin }
+    }
+}
+
+impl<T> Drop for NoDropLogMsg<'_, T> {
+    /// Emits `msg` as a [`tracing`] error event.
+    fn drop(&mut self) {
+        log_with(&self.msg, self.origin);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::no_drop::test_macros::{test_clone, test_ctor, test_forget};
+
+    test_ctor!(log_empty_wrap, NoDropLogEmpty::wrap, (42), 42);
+    test_ctor!(log_empty_new, NoDropLogEmpty::new, (), ());
+    test_ctor!(log_empty_default, NoDropLogEmpty::default, (), ());
+    test_ctor!(log_msg_wrap, NoDropLogMsg::wrap, (42, "message"), 42);
+    test_ctor!(log_msg_guard, NoDropLogMsg::guard, ("message"), ());
+
+    test_clone!(log_empty_clone, NoDropLogEmpty, NoDropLogEmpty::new, ());
+    test_clone!(log_msg_clone, NoDropLogMsg, NoDropLogMsg::guard, ("message"));
+
+    test_forget!(log_empty_forget, NoDropLogEmpty::wrap, (42));
+    test_forget!(log_msg_forget, NoDropLogMsg::wrap, (42, "message"));
+
+    #[test]
+    fn drop_does_not_panic() {
+        drop(NoDropLogEmpty::wrap(42));
+        drop(NoDropLogMsg::wrap(42, "message"));
+    }
+}
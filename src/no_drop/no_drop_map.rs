@@ -0,0 +1,271 @@
+use std::borrow::Borrow;
+use std::collections::hash_map::{Keys, Values};
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+
+use super::NoDropEmpty;
+
+/// Forwarding methods for guarded [`HashMap`]s.
+///
+/// All methods leave the guard armed, unless noted otherwise.
+impl<K, V, S> NoDropEmpty<HashMap<K, V, S>> {
+    /// Returns an iterator over the keys of the guarded map, in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(HashMap::from([("a", 1)]));
+    /// assert_eq!(wrapper.keys().collect::<Vec<_>>(), vec![&"a"]);
+    /// wrapper.forget();
+    /// ```
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        HashMap::keys(self)
+    }
+
+    /// Returns an iterator over the values of the guarded map, in arbitrary order.
+    pub fn values(&self) -> Values<'_, K, V> {
+        HashMap::values(self)
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> NoDropEmpty<HashMap<K, V, S>> {
+    /// Returns `true` if the guarded map contains a value for `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(HashMap::from([("a", 1)]));
+    /// assert!(wrapper.contains_key(&"a"));
+    /// assert_eq!(wrapper.get(&"a"), Some(&1));
+    /// wrapper.forget();
+    /// ```
+    pub fn contains_key(&self, key: &K) -> bool {
+        HashMap::contains_key(self, key)
+    }
+
+    /// Returns a reference to the value for `key` in the guarded map, if present.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        HashMap::get(self, key)
+    }
+
+    /// Inserts every key-value pair from `iter` into the guarded map, overwriting the value of any
+    /// existing key.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let mut wrapper = NoDrop::wrap(HashMap::from([("a", 1)]));
+    /// wrapper.extend_entries([("a", 2), ("b", 3)]);
+    /// assert_eq!(wrapper.consume(), HashMap::from([("a", 2), ("b", 3)]));
+    /// ```
+    pub fn extend_entries(&mut self, iter: impl IntoIterator<Item = (K, V)>) {
+        HashMap::extend(self, iter);
+    }
+
+    /// Consumes the guard, passing each value of the map through `f`, into a newly guarded map with
+    /// the same keys.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(HashMap::from([("a", 1), ("b", 2)]));
+    /// let doubled = wrapper.map_values(|n| n * 2);
+    /// assert_eq!(doubled.consume(), HashMap::from([("a", 2), ("b", 4)]));
+    /// ```
+    pub fn map_values<W>(self, mut f: impl FnMut(V) -> W) -> NoDropEmpty<HashMap<K, W, S>>
+    where
+        S: Default,
+    {
+        self.map(|map| map.into_iter().map(|(key, value)| (key, f(value))).collect())
+    }
+}
+
+impl<T> NoDropEmpty<Vec<T>> {
+    /// Consumes the guard, grouping the vector's elements by the key `f` returns for each, into a
+    /// newly guarded map. Elements keep their relative order within each group.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(vec![1, 2, 3, 4, 5]);
+    /// let groups = wrapper.group_by_key(|n| n % 2 == 0);
+    /// assert_eq!(groups.consume(), HashMap::from([(false, vec![1, 3, 5]), (true, vec![2, 4])]));
+    /// ```
+    pub fn group_by_key<K: Eq + Hash>(self, mut f: impl FnMut(&T) -> K) -> NoDropEmpty<HashMap<K, Vec<T>>> {
+        self.map(|values| {
+            let mut groups: HashMap<K, Vec<T>> = HashMap::new();
+            for value in values {
+                groups.entry(f(&value)).or_default().push(value);
+            }
+            groups
+        })
+    }
+}
+
+/// Removes the guard for `key` from `map` and consumes it, returning its value.
+///
+/// Removing a guard with [`HashMap::remove`] and dropping the returned [`Option`] without
+/// consuming it [`panic!`]s. This consumes the guard instead, so the value can simply be ignored
+/// when it is not needed.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use no_drop::rls::{remove_and_consume, NoDrop};
+///
+/// let mut tasks = HashMap::from([(1, NoDrop::wrap("upload"))]);
+/// assert_eq!(remove_and_consume(&mut tasks, &1), Some("upload"));
+/// assert_eq!(remove_and_consume(&mut tasks, &1), None);
+/// ```
+pub fn remove_and_consume<K, Q, T, S>(map: &mut HashMap<K, NoDropEmpty<T>, S>, key: &Q) -> Option<T>
+where
+    K: Eq + Hash + Borrow<Q>,
+    Q: Eq + Hash + ?Sized,
+    S: BuildHasher,
+{
+    map.remove(key).map(NoDropEmpty::consume)
+}
+
+/// Removes every guard from `map`, consuming each and passing its key and value to `f`, in
+/// arbitrary order.
+///
+/// The map is empty afterwards, but keeps its allocated capacity.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use no_drop::rls::{drain_consume, NoDrop};
+///
+/// let mut tasks = HashMap::from([(1, NoDrop::wrap(10)), (2, NoDrop::wrap(20))]);
+/// let mut total = 0;
+/// drain_consume(&mut tasks, |_, value| total += value);
+/// assert_eq!(total, 30);
+/// assert!(tasks.is_empty());
+/// ```
+pub fn drain_consume<K, T, S>(map: &mut HashMap<K, NoDropEmpty<T>, S>, mut f: impl FnMut(K, T)) {
+    map.drain().for_each(|(key, guard)| f(key, guard.consume()));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::no_drop::NoDropPassEmpty;
+
+    fn map() -> HashMap<&'static str, i32> {
+        HashMap::from([("a", 1), ("b", 2), ("c", 3)])
+    }
+
+    #[test]
+    fn keys_and_values() {
+        let wrapper = NoDropEmpty::wrap(map());
+
+        assert_eq!(wrapper.keys().copied().collect::<HashSet<_>>(), HashSet::from(["a", "b", "c"]));
+        assert_eq!(wrapper.values().copied().collect::<HashSet<_>>(), HashSet::from([1, 2, 3]));
+        assert_eq!(wrapper.consume(), map());
+    }
+
+    #[test]
+    fn contains_key_and_get() {
+        let wrapper = NoDropEmpty::wrap(map());
+
+        assert!(wrapper.contains_key(&"a"));
+        assert!(!wrapper.contains_key(&"z"));
+        assert_eq!(wrapper.get(&"b"), Some(&2));
+        assert_eq!(wrapper.get(&"z"), None);
+        assert_eq!(wrapper.consume(), map());
+    }
+
+    #[test]
+    fn extend_entries_overwrites() {
+        let mut wrapper = NoDropEmpty::wrap(map());
+        wrapper.extend_entries([("a", 10), ("d", 4)]);
+        assert_eq!(wrapper.consume(), HashMap::from([("a", 10), ("b", 2), ("c", 3), ("d", 4)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn extend_entries_stays_armed() {
+        let mut wrapper = NoDropEmpty::wrap(map());
+        wrapper.extend_entries([("d", 4)]);
+        drop(wrapper);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn keys_stays_armed() {
+        let wrapper = NoDropEmpty::wrap(map());
+        assert_eq!(wrapper.keys().count(), 3);
+        drop(wrapper);
+    }
+
+    #[test]
+    fn group_by_key_parity() {
+        let expected = HashMap::from([(1, vec![1, 3, 5]), (0, vec![2, 4, 6])]);
+
+        let wrapper = NoDropEmpty::wrap(vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(wrapper.group_by_key(|n| n % 2).consume(), expected);
+
+        let wrapper = NoDropPassEmpty::wrap(vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(wrapper.group_by_key(|n| n % 2).consume(), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn group_by_key_stays_armed() {
+        let wrapper = NoDropEmpty::wrap(vec![1, 2, 3]);
+        drop(wrapper.group_by_key(|n| n % 2));
+    }
+
+    #[test]
+    fn remove_and_consume_returns_value() {
+        let mut guards =
+            HashMap::from([("a".to_string(), NoDropEmpty::wrap(1)), ("b".to_string(), NoDropEmpty::wrap(2))]);
+        assert_eq!(remove_and_consume(&mut guards, "a"), Some(1));
+        assert_eq!(remove_and_consume(&mut guards, "a"), None);
+        assert_eq!(guards.len(), 1);
+        drain_consume(&mut guards, |_, _| {});
+    }
+
+    #[test]
+    fn drain_consume_clears_map() {
+        let mut guards: HashMap<_, _> = map().into_iter().map(|(key, value)| (key, NoDropEmpty::wrap(value))).collect();
+        let mut seen = HashSet::new();
+        drain_consume(&mut guards, |key, value| assert!(seen.insert((key, value))));
+        assert!(guards.is_empty());
+        assert_eq!(seen.len(), 3);
+    }
+
+    #[test]
+    fn map_values_transforms_values() {
+        let wrapper = NoDropEmpty::wrap(map());
+        let mapped = wrapper.map_values(|n| n.to_string());
+        let expected: HashMap<_, _> = map().into_iter().map(|(key, value)| (key, value.to_string())).collect();
+        assert_eq!(mapped.consume(), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn map_values_stays_armed() {
+        let wrapper = NoDropEmpty::wrap(map());
+        drop(wrapper.map_values(|n| n.to_string()));
+    }
+}
@@ -1,32 +1,57 @@
-use std::borrow::Cow;
-use std::mem::ManuallyDrop;
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::sync::Arc;
+use core::borrow::{Borrow, BorrowMut};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::mem::ManuallyDrop;
+use core::panic::RefUnwindSafe;
+use core::pin::Pin;
+
+use super::leak::panic_with_trace;
+use super::{Message, NoDropEmpty, Origin, Trace, DEFAULT_DROP_PANIC_MSG};
 
 /// A wrapper around a `T` `value` with a custom panic `msg` and will [`panic!`]s if dropped without being
-/// [`Self::unwrap`]ped or [`Self::forget`]ten.
+/// [`Self::consume`]d or [`Self::forget`]ten.
 ///
 /// The lifetime parameter `'msg` allows borrowing the message, and most commonly will be `'static`.
-#[derive(
-    PartialEq,
-    Eq,
-    PartialOrd,
-    Ord,
-    Hash,
-    Debug,
-    derive_more::Deref,
-    derive_more::DerefMut,
-    derive_more::AsMut,
-    derive_more::AsRef,
-)]
-#[must_use]
+/// The message may also be computed lazily, only if needed, via [`Self::wrap_with`].
+///
+/// Comparisons and hashing only consider the value, consistent with its [`Borrow`] implementation,
+/// so a wrapper can be looked up in a collection by `&T`. The location the wrapper was created at
+/// is reported in the [`panic!`] message.
+///
+/// With the `serde` feature, this type (de)serializes as a struct with `value` and `msg` fields.
+/// A deserialized value is armed, with an owned `msg`, and must be consumed like any other.
+///
+/// With the `backtrace` feature, a backtrace is captured when the wrapper is created, and included
+/// in the [`panic!`] message.
+///
+/// The wrapper is [`Send`] and [`Sync`] whenever `T` is, since lazy messages are required to be
+/// both, so it may be held across an `.await` in a multi-threaded executor. See
+/// [`Self::cancelled_msg`] for guarding work a future must finish. It is also [`Unpin`] and unwind
+/// safe whenever `T` is, and covariant in both `'msg` and `T`.
+#[derive(Debug, derive_more::Deref, derive_more::DerefMut, derive_more::AsMut, derive_more::AsRef)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use = "the value must be consumed, or it will panic when dropped"]
 pub struct NoDropMsg<'msg, T = ()> {
     #[deref]
     #[deref_mut]
     #[as_mut]
     #[as_ref]
     value: T,
-    msg: Cow<'msg, str>,
+    msg: Message<'msg>,
+    #[cfg_attr(feature = "serde", serde(skip, default = "Origin::caller"))]
+    origin: Origin,
+    #[cfg_attr(feature = "serde", serde(skip, default = "Trace::capture"))]
+    trace: Trace,
 }
 
+/// The [`panic!`] message used by [`NoDropMsg::cancelled_msg`].
+pub const CANCELLED_DROP_PANIC_MSG: &str = "Future was cancelled before the guarded value was consumed";
+
 impl<'msg, T> NoDropMsg<'msg, T> {
     /// Creates a new wrapper around `value` with a custom [`panic!`] `msg`.
     ///
@@ -36,27 +61,336 @@ impl<'msg, T> NoDropMsg<'msg, T> {
     /// use no_drop::rls::NoDropMsg;
     ///
     /// let wrapper = NoDropMsg::wrap(42, "forgot to process this value");
-    /// assert_eq!(wrapper.unwrap(), 42);
+    /// assert_eq!(wrapper.consume(), 42);
     /// ```
     #[inline]
+    #[track_caller]
     pub fn wrap<M: Into<Cow<'msg, str>>>(value: T, msg: M) -> Self {
-        Self { value, msg: msg.into() }
+        Self { value, msg: Message::from(msg.into()), origin: Origin::caller(), trace: Trace::capture() }
+    }
+
+    /// Creates a new wrapper around `value` with a [`panic!`] message computed by `f`.
+    ///
+    /// `f` is only called if the message is needed, such as when dropped without being consumed,
+    /// so expensive formatting is skipped on the happy path. Since clones of a guard share `f`, it
+    /// must be [`Fn`] rather than [`FnOnce`]. It must also be [`Send`], [`Sync`], and
+    /// [`RefUnwindSafe`], so the wrapper keeps those traits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDropMsg;
+    ///
+    /// let id = 7;
+    /// let wrapper = NoDropMsg::wrap_with(42, move || format!("request {id} was not processed"));
+    /// assert_eq!(wrapper.consume(), 42);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn wrap_with<F: Fn() -> String + Send + Sync + RefUnwindSafe + 'msg>(value: T, f: F) -> Self {
+        Self { value, msg: Message::Lazy(Arc::new(f)), origin: Origin::caller(), trace: Trace::capture() }
+    }
+
+    /// Creates a new wrapper around `value` with [`CANCELLED_DROP_PANIC_MSG`] as its message.
+    ///
+    /// Intended for values held across `.await` points, where the only way to drop the wrapper
+    /// without consuming it is usually for the future to be cancelled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDropMsg;
+    ///
+    /// async fn commit(transaction: Vec<u8>) {}
+    ///
+    /// async fn run() {
+    ///     let transaction = NoDropMsg::cancelled_msg(vec![1, 2, 3]);
+    ///     commit(transaction.consume()).await;
+    /// }
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn cancelled_msg(value: T) -> Self {
+        Self::wrap(value, CANCELLED_DROP_PANIC_MSG)
+    }
+
+    /// Returns a reference to the inner `T`, without going through [`Deref`](core::ops::Deref).
+    ///
+    /// Unlike auto-deref, this is never shadowed by a method of `T` with the same name.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns a mutable reference to the inner `T`, without going through
+    /// [`DerefMut`](core::ops::DerefMut).
+    #[inline]
+    #[must_use]
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.value
     }
 
     /// Consumes the wrapper and returns the inner `T`.
     #[inline]
     #[must_use]
-    pub fn unwrap(self) -> T {
+    pub fn consume(self) -> T {
+        self.into_parts().0
+    }
+
+    /// Consumes the wrapper and returns the inner `T`, the same as [`Self::consume`].
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.consume()
+    }
+
+    /// Consumes the wrapper, passing the inner `T` to `f` and returning its result.
+    ///
+    /// If `f` [`panic!`]s, the value is dropped normally as part of the unwind, and the guard does
+    /// not [`panic!`] again.
+    #[inline]
+    pub fn consume_map<U>(self, f: impl FnOnce(T) -> U) -> U {
+        f(self.consume())
+    }
+
+    /// Consumes the wrapper, converting the inner `T` into a `U`.
+    #[inline]
+    pub fn consume_into<U: From<T>>(self) -> U {
+        self.consume().into()
+    }
+
+    /// Consumes the wrapper, applying `f` to the inner `T` and re-wrapping the result with the same
+    /// `msg`.
+    ///
+    /// If `f` [`panic!`]s, the value and message are dropped normally as part of the unwind, and
+    /// the guard does not [`panic!`] again.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDropMsg;
+    ///
+    /// let wrapper = NoDropMsg::wrap(21, "answer not processed");
+    /// assert_eq!(wrapper.map(|n| n * 2).consume(), 42);
+    /// ```
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> NoDropMsg<'msg, U> {
+        let origin = self.origin;
+        let (value, msg, trace) = self.into_parts();
+        NoDropMsg { value: f(value), msg, origin, trace }
+    }
+
+    /// Rewrites the panic message with `f`, leaving the guard armed.
+    ///
+    /// A lazy message is computed before being passed to `f`.
+    ///
+    /// If `f` [`panic!`]s, the value is dropped normally as part of the unwind, and the guard does
+    /// not [`panic!`] again.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,should_panic
+    /// use no_drop::rls::NoDropMsg;
+    ///
+    /// let wrapper = NoDropMsg::wrap(42, "not processed");
+    /// let wrapper = wrapper.map_msg(|msg| format!("stage 2: {msg}").into());
+    /// drop(wrapper); // panic: "stage 2: not processed"
+    /// ```
+    pub fn map_msg(self, f: impl FnOnce(Cow<'msg, str>) -> Cow<'msg, str>) -> Self {
+        let origin = self.origin;
+        let (value, msg, trace) = self.into_parts();
+        Self { msg: Message::from(f(msg.into_cow())), value, origin, trace }
+    }
+
+    /// Returns the current panic message.
+    ///
+    /// A lazy message is computed each time this is called, so an owned [`Cow`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDropMsg;
+    ///
+    /// let wrapper = NoDropMsg::wrap(42, "not processed");
+    /// assert_eq!(wrapper.msg(), "not processed");
+    /// wrapper.forget();
+    /// ```
+    #[must_use]
+    pub fn msg(&self) -> Cow<'_, str> {
+        self.msg.resolve()
+    }
+
+    /// Replaces the panic message with `msg`, leaving the guard armed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,should_panic
+    /// use no_drop::rls::NoDropMsg;
+    ///
+    /// let mut wrapper = NoDropMsg::wrap(42, "not processed");
+    /// wrapper.set_msg("not processed after stage 2");
+    /// drop(wrapper); // panic: "not processed after stage 2"
+    /// ```
+    pub fn set_msg<M: Into<Cow<'msg, str>>>(&mut self, msg: M) {
+        self.msg = Message::from(msg.into());
+    }
+
+    /// Appends `more` to the panic message, separated by `": "`, leaving the guard armed.
+    ///
+    /// The message becomes an owned [`String`], with a lazy message computed first. Chaining calls
+    /// records each stage of a pipeline the guard passed through.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,should_panic
+    /// use no_drop::rls::NoDropMsg;
+    ///
+    /// let wrapper = NoDropMsg::guard("not processed").context("stage A").context("stage B");
+    /// drop(wrapper); // panic: "not processed: stage A: stage B"
+    /// ```
+    pub fn context(mut self, more: impl fmt::Display) -> Self {
+        self.msg = Message::Owned(alloc::format!("{}: {more}", self.msg));
+        self
+    }
+
+    /// Consumes the wrapper, moving the inner `T` into a [`NoDropEmpty`], discarding the message.
+    ///
+    /// The value is never dropped or consumed in between, so the obligation carries over to the
+    /// new guard, which [`panic!`]s with the default message and reports the original creation
+    /// location.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDropMsg;
+    ///
+    /// let wrapper = NoDropMsg::wrap(42, "answer was not processed").without_msg();
+    /// assert_eq!(wrapper.consume(), 42);
+    /// ```
+    pub fn without_msg(self) -> NoDropEmpty<T> {
+        let origin = self.origin;
+        NoDropEmpty::from_parts(self.into_parts().0, origin)
+    }
+
+    /// Creates a new wrapper around `value` with `msg`, reporting `origin` as its creation
+    /// location.
+    pub(crate) fn from_parts(value: T, msg: Message<'msg>, origin: Origin) -> Self {
+        Self { value, msg, origin, trace: Trace::capture() }
+    }
+
+    /// Consumes the wrapper, returning the inner `T`, `msg`, and `trace`.
+    fn into_parts(self) -> (T, Message<'msg>, Trace) {
         let this = ManuallyDrop::new(self);
-        // SAFETY: `T` is moved out of the wrapper exactly once, then this is dropped.
-        // No uninitialized access can occur.
-        unsafe { std::ptr::read(&raw const this.value) }
+        // SAFETY: `T`, `msg`, and `trace` are each moved out of the wrapper exactly once, then
+        // this is dropped. No uninitialized access can occur.
+        unsafe {
+            (
+                core::ptr::read(&raw const this.value),
+                core::ptr::read(&raw const this.msg),
+                core::ptr::read(&raw const this.trace),
+            )
+        }
+    }
+
+    /// Consumes the wrapper and returns the inner `T`.
+    #[deprecated(since = "0.3.0", note = "renamed to `consume`")]
+    #[inline]
+    #[must_use]
+    pub fn unwrap(self) -> T {
+        self.consume()
+    }
+
+    /// Replaces the inner `T` with `value`, returning the old value, while the wrapper stays armed.
+    ///
+    /// The returned value is a plain owned `T`, no longer guarded. This is an associated function,
+    /// called as `NoDropMsg::replace(&mut wrapper, value)`, so it does not shadow any `replace`
+    /// method of `T`, such as [`Option::replace`].
+    #[inline]
+    #[must_use]
+    pub fn replace(this: &mut Self, value: T) -> T {
+        core::mem::replace(&mut this.value, value)
+    }
+
+    /// Swaps the inner values of two wrappers. Each keeps its own message and creation location, and
+    /// both stay armed.
+    ///
+    /// This is an associated function, called as `NoDropMsg::swap(&mut a, &mut b)`, so it does not
+    /// shadow any `swap` method of `T`, such as [`slice::swap`].
+    #[inline]
+    pub fn swap(this: &mut Self, other: &mut Self) {
+        core::mem::swap(&mut this.value, &mut other.value);
     }
 
     /// Forgets this guard, safely dropping it.
     #[inline]
     pub fn forget(self) {
-        let _ = ManuallyDrop::new(self);
+        drop(self.into_parts());
+    }
+
+    /// Forgets this guard like [`Self::forget`], noting it and its message on stderr in debug
+    /// builds.
+    ///
+    /// It never [`panic!`]s, and without `std`, or in release builds, this is identical to
+    /// [`Self::forget`].
+    #[inline]
+    pub fn debug_forget(self) {
+        #[cfg(all(feature = "std", debug_assertions))]
+        super::notice_forgotten(&self.msg, self.origin);
+        self.forget();
+    }
+}
+
+/// Pin projection, for guarding `!Unpin` values.
+///
+/// The wrapper never moves its value while it is pinned, so the value is structurally pinned. Only
+/// [`Self::consume`] moves it, which requires an owned, and so unpinned, wrapper. A pinned wrapper
+/// can only be consumed via [`Self::consume_pinned`] if `T: Unpin`, or the `unsafe`
+/// [`Self::consume_pinned_unchecked`] otherwise.
+impl<T> NoDropMsg<'_, T> {
+    /// Projects a pinned reference to the wrapper to a pinned reference to its value.
+    pub fn as_pin_ref(self: Pin<&Self>) -> Pin<&T> {
+        // SAFETY: The value is structurally pinned, see above.
+        unsafe { self.map_unchecked(|this| &this.value) }
+    }
+
+    /// Projects a pinned mutable reference to the wrapper to a pinned mutable reference to its
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::marker::PhantomPinned;
+    /// use no_drop::rls::NoDropMsg;
+    ///
+    /// let mut wrapper = Box::pin(NoDropMsg::wrap(PhantomPinned, "message"));
+    /// let _value: core::pin::Pin<&mut PhantomPinned> = wrapper.as_mut().as_pin_mut();
+    /// // SAFETY: `PhantomPinned` does not rely on its address.
+    /// let _value = unsafe { wrapper.consume_pinned_unchecked() };
+    /// ```
+    pub fn as_pin_mut(self: Pin<&mut Self>) -> Pin<&mut T> {
+        // SAFETY: The value is structurally pinned, see above.
+        unsafe { self.map_unchecked_mut(|this| &mut this.value) }
+    }
+
+    /// Consumes a pinned, boxed, wrapper and returns the inner `T`.
+    #[must_use]
+    pub fn consume_pinned(self: Pin<Box<Self>>) -> T
+    where
+        T: Unpin,
+    {
+        Pin::into_inner(self).consume()
+    }
+
+    /// Consumes a pinned, boxed, wrapper and returns the inner `T`, moving it out of the pin.
+    ///
+    /// # Safety
+    ///
+    /// Moving the value must not violate its pinning guarantees. That is, the value must not rely
+    /// on its address remaining stable, such as being self-referential.
+    #[must_use]
+    pub unsafe fn consume_pinned_unchecked(self: Pin<Box<Self>>) -> T {
+        // SAFETY: The caller guarantees the value may be moved.
+        unsafe { Pin::into_inner_unchecked(self) }.consume()
     }
 }
 
@@ -71,39 +405,205 @@ impl<'msg> NoDropMsg<'msg, ()> {
     /// let wrapper = NoDropMsg::guard("this should be consumed");
     /// wrapper.forget();
     /// ```
+    #[track_caller]
     pub fn guard<M: Into<Cow<'msg, str>>>(msg: M) -> Self {
-        Self { value: (), msg: msg.into() }
+        Self::from_message(Message::from(msg.into()))
     }
 
-    /// Consumes the guard and returns the inner panic message.
-    pub(crate) fn unwrap_msg(self) -> Cow<'msg, str> {
-        let this = ManuallyDrop::new(self);
-        // SAFETY: `msg` is moved out of the wrapper exactly once, then this is dropped.
-        // No uninitialized access can occur.
-        unsafe { std::ptr::read(&raw const this.msg) }
+    /// Creates a new empty [`NoDropMsg`] guard with a panic message formatted from `args`.
+    ///
+    /// A message without arguments is borrowed without allocating. Otherwise it is formatted
+    /// immediately, since [`fmt::Arguments`] cannot outlive the statement that created it. Use
+    /// [`no_drop_msg!`](crate::rls::no_drop_msg) to defer the formatting until the guard [`panic!`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDropMsg;
+    ///
+    /// let id = 7;
+    /// let wrapper = NoDropMsg::guard_fmt(format_args!("request {id} was not processed"));
+    /// wrapper.forget();
+    /// ```
+    #[track_caller]
+    pub fn guard_fmt(args: fmt::Arguments<'_>) -> Self {
+        Self::from_message(Message::from_args(args))
+    }
+
+    /// Creates a new empty guard from an existing `msg`.
+    #[track_caller]
+    pub(crate) fn from_message(msg: Message<'msg>) -> Self {
+        Self { value: (), msg, origin: Origin::caller(), trace: Trace::capture() }
+    }
+
+    /// Consumes the guard and returns the inner panic message, without computing it.
+    pub(crate) fn into_message(self) -> Message<'msg> {
+        self.into_parts().1
+    }
+}
+
+/// Creates an armed guard with [`DEFAULT_DROP_PANIC_MSG`] as its message.
+///
+/// Unlike a default [`DropGuard`](crate::rls::DropGuard), which is disarmed, this [`panic!`]s if
+/// dropped without being consumed, like every [`NoDropMsg`].
+impl Default for NoDropMsg<'_, ()> {
+    #[track_caller]
+    fn default() -> Self {
+        Self::guard(DEFAULT_DROP_PANIC_MSG)
     }
 }
 
 impl<'msg> Clone for NoDropMsg<'msg, ()> {
     fn clone(&self) -> Self {
-        Self { value: (), msg: self.msg.clone() }
+        Self { value: (), msg: self.msg.clone(), origin: self.origin, trace: self.trace.clone() }
+    }
+}
+
+impl<T: PartialEq> PartialEq for NoDropMsg<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq> Eq for NoDropMsg<'_, T> {}
+
+impl<T: PartialOrd> PartialOrd for NoDropMsg<'_, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: Ord> Ord for NoDropMsg<'_, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T: Hash> Hash for NoDropMsg<'_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl<T> Borrow<T> for NoDropMsg<'_, T> {
+    fn borrow(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> BorrowMut<T> for NoDropMsg<'_, T> {
+    fn borrow_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T> From<NoDropMsg<'_, T>> for (T, String) {
+    /// Consumes the wrapper, returning the inner `T` and its message, computed if lazy.
+    fn from(no_drop: NoDropMsg<'_, T>) -> Self {
+        let (value, msg, _) = no_drop.into_parts();
+        (value, msg.into_cow().into_owned())
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for NoDropMsg<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T: fmt::Pointer> fmt::Pointer for NoDropMsg<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
     }
 }
 
 impl<'msg, T> Drop for NoDropMsg<'msg, T> {
-    /// [`panic!`]s with `msg`.
+    /// [`panic!`]s with `msg`, unless the thread is already panicking.
+    ///
+    /// Only reached if the value was not consumed, so this is kept out of line.
+    #[cold]
+    #[inline(never)]
     #[track_caller]
     fn drop(&mut self) {
-        panic!("{}", self.msg);
+        panic_with_trace(&self.msg, self.origin, &self.trace);
     }
 }
 
+/// Creates a [`NoDropMsg`](crate::rls::NoDropMsg) with a [`format!`](alloc::format!)-style panic
+/// message, formatted only if the guard [`panic!`]s.
+///
+/// `no_drop_msg!(value, "fmt", args...)` expands to [`NoDropMsg::wrap_with`] with a closure that
+/// formats the message. The closure borrows any arguments, so the wrapper cannot outlive them, and
+/// they must be [`Sync`] and [`RefUnwindSafe`]. For a message that owns its arguments, use
+/// [`NoDropMsg::wrap_with`] with a `move` closure.
+///
+/// This is exported as `rls::no_drop_msg!`, and as `dbg::no_drop_msg!`, which creates a
+/// `dbg::NoDropMsg` instead, so never formats the message in release builds.
+///
+/// # Examples
+///
+/// ```rust
+/// use no_drop::rls::no_drop_msg;
+///
+/// let id = 7;
+/// let wrapper = no_drop_msg!(42, "request {id} was not processed");
+/// assert_eq!(wrapper.consume(), 42);
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __no_drop_msg_rls {
+    ($value:expr, $($arg:tt)+) => {
+        $crate::rls::NoDropMsg::wrap_with($value, || $crate::__private::format!($($arg)+))
+    };
+}
+
+/// Creates a [`dbg::NoDropMsg`](crate::dbg::NoDropMsg) with a [`format!`](alloc::format!)-style
+/// panic message, formatted only if the guard [`panic!`]s.
+///
+/// This is the `dbg` counterpart of `rls::no_drop_msg!`. In release builds the passthrough type
+/// discards the closure, so the message is never formatted.
+///
+/// # Examples
+///
+/// ```rust
+/// use no_drop::dbg::no_drop_msg;
+///
+/// let id = 7;
+/// let wrapper = no_drop_msg!(42, "request {id} was not processed");
+/// assert_eq!(wrapper.consume(), 42);
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __no_drop_msg_dbg {
+    ($value:expr, $($arg:tt)+) => {
+        $crate::dbg::NoDropMsg::wrap_with($value, || $crate::__private::format!($($arg)+))
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::into::{IntoNoDropDbg, IntoNoDropRls};
     use crate::no_drop::test_macros::{test_clone, test_ctor, test_forget};
 
+    #[test]
+    fn forget_drops_value() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        NoDropMsg::wrap(Rc::clone(&rc), "message").forget();
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn debug_forget_drops_value() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        NoDropMsg::wrap(Rc::clone(&rc), "message").debug_forget();
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
     #[test]
     #[should_panic(expected = "custom panic message")]
     fn no_drop_msg_panics() {
@@ -111,6 +611,32 @@ mod tests {
         drop(wrapper);
     }
 
+    #[test]
+    fn replace_returns_unguarded_old_value() {
+        let mut wrapper = NoDropMsg::wrap(String::from("old"), "message");
+        let old = NoDropMsg::replace(&mut wrapper, String::from("new"));
+        drop(old);
+        assert_eq!(wrapper.consume(), "new");
+    }
+
+    #[test]
+    #[should_panic(expected = "message")]
+    fn replace_stays_armed() {
+        let mut wrapper = NoDropMsg::wrap(1, "message");
+        let _ = NoDropMsg::replace(&mut wrapper, 2);
+        drop(wrapper);
+    }
+
+    #[test]
+    #[should_panic(expected = "b")]
+    fn swap_keeps_messages() {
+        let mut a = NoDropMsg::wrap(1, "a");
+        let mut b = NoDropMsg::wrap(2, "b");
+        NoDropMsg::swap(&mut a, &mut b);
+        assert_eq!(a.consume(), 2);
+        drop(b);
+    }
+
     test_ctor!(no_drop_msg_static_str, NoDropMsg::wrap, (42, "custom message"), 42);
     test_ctor!(no_drop_msg_string, NoDropMsg::wrap, (42, String::from("owned message")), 42);
 
@@ -128,7 +654,145 @@ mod tests {
     fn no_drop_msg_borrowed() {
         let msg = String::from("borrowed message");
         let wrapper = NoDropMsg::wrap(42, msg.as_str());
-        assert_eq!(wrapper.unwrap(), 42);
+        assert_eq!(wrapper.consume(), 42);
+    }
+
+    #[test]
+    fn into_inner_returns_value() {
+        assert_eq!(NoDropMsg::wrap(42, "message").into_inner(), 42);
+    }
+
+    #[test]
+    fn into_value_and_msg() {
+        let (value, msg) = NoDropMsg::wrap(42, "message").into();
+        assert_eq!((value, msg), (42, String::from("message")));
+
+        let (value, msg) = NoDropMsg::wrap_with(42, || String::from("lazy message")).into();
+        assert_eq!((value, msg), (42, String::from("lazy message")));
+    }
+
+    #[test]
+    fn guard_fmt_borrows_literal() {
+        let wrapper = NoDropMsg::guard_fmt(format_args!("literal message"));
+        assert!(matches!(wrapper.msg, Message::Borrowed("literal message")));
+        wrapper.forget();
+    }
+
+    #[test]
+    #[should_panic(expected = "request 7 was not processed")]
+    fn guard_fmt_formats_args() {
+        let id = 7;
+        drop(NoDropMsg::guard_fmt(format_args!("request {id} was not processed")));
+    }
+
+    #[test]
+    fn no_drop_msg_macro_formats_only_on_panic() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static RENDERS: AtomicUsize = AtomicUsize::new(0);
+
+        struct Id(u32);
+
+        impl fmt::Display for Id {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                RENDERS.fetch_add(1, Ordering::SeqCst);
+                write!(f, "{}", self.0)
+            }
+        }
+
+        let id = Id(7);
+        assert_eq!(crate::rls::no_drop_msg!(42, "request {id} was not processed").consume(), 42);
+        assert_eq!(RENDERS.load(Ordering::SeqCst), 0);
+
+        let result =
+            std::panic::catch_unwind(|| drop(crate::rls::no_drop_msg!(42, "request {} was not processed", id)));
+        let payload = result.unwrap_err();
+        assert!(payload.downcast_ref::<String>().unwrap().starts_with("request 7 was not processed"));
+        assert_eq!(RENDERS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn dbg_no_drop_msg_macro_creates_dbg_type() {
+        let id = 7;
+        let wrapper: crate::dbg::NoDropMsg<'_, i32> = crate::dbg::no_drop_msg!(42, "request {id} was not processed");
+        assert_eq!(wrapper.consume(), 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "base: stage A: stage B (created at ")]
+    fn context_appends_to_msg() {
+        let wrapper = NoDropMsg::guard("base").context("stage A").context("stage B");
+        drop(wrapper);
+    }
+
+    #[test]
+    fn context_resolves_lazy_msg() {
+        let wrapper = NoDropMsg::wrap_with(42, || String::from("lazy")).context(7);
+        assert!(matches!(&wrapper.msg, Message::Owned(msg) if msg == "lazy: 7"));
+        assert_eq!(wrapper.consume(), 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn default_is_armed() {
+        drop(NoDropMsg::default());
+    }
+
+    #[test]
+    fn map_keeps_msg() {
+        let wrapper = NoDropMsg::wrap(21, "message").map(|n| n * 2);
+        assert_eq!(wrapper.msg.resolve(), "message");
+        assert_eq!(wrapper.consume(), 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "mapped value not consumed")]
+    fn map_stays_armed() {
+        let wrapper = NoDropMsg::wrap(21, "mapped value not consumed").map(|n| n * 2);
+        drop(wrapper);
+    }
+
+    #[test]
+    #[should_panic(expected = "rewritten message")]
+    fn map_msg_rewrites_msg() {
+        let wrapper = NoDropMsg::wrap(42, "original").map_msg(|_| Cow::Borrowed("rewritten message"));
+        drop(wrapper);
+    }
+
+    #[test]
+    fn set_msg_replaces_msg() {
+        let mut wrapper = NoDropMsg::wrap_with(42, || String::from("original"));
+        assert_eq!(wrapper.msg(), "original");
+        wrapper.set_msg(String::from("replaced message"));
+        assert_eq!(wrapper.msg(), "replaced message");
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| drop(wrapper)));
+        let payload = result.unwrap_err();
+        assert!(payload.downcast_ref::<String>().unwrap().starts_with("replaced message"));
+    }
+
+    #[test]
+    fn map_panicking_drops_value_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+        struct Payload;
+
+        impl Drop for Payload {
+            fn drop(&mut self) {
+                DROPS.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let wrapper = NoDropMsg::wrap(Payload, "guard message");
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _ = wrapper.map(|_payload| -> () { panic!("map failed") });
+        }));
+
+        let payload = result.unwrap_err();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"map failed"));
+        assert_eq!(DROPS.load(Ordering::SeqCst), 1);
     }
 
     #[test]
@@ -137,4 +801,243 @@ mod tests {
         let wrapper = NoDropMsg::guard("unit value must be consumed");
         drop(wrapper);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let original = NoDropMsg::wrap(42, "custom message");
+        let json = serde_json::to_string(&original).unwrap();
+        assert_eq!(json, r#"{"value":42,"msg":"custom message"}"#);
+        original.forget();
+
+        let wrapper: NoDropMsg<'static, i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(wrapper.consume(), 42);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    #[should_panic(expected = "deserialized message")]
+    fn serde_deserialized_panics() {
+        let wrapper: NoDropMsg<'static, i32> =
+            serde_json::from_str(r#"{"value":42,"msg":"deserialized message"}"#).unwrap();
+        drop(wrapper);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn drop_during_unwind_does_not_abort() {
+        let result = std::panic::catch_unwind(|| {
+            let _wrapper = NoDropMsg::wrap(42, "custom message");
+            panic!("first panic");
+        });
+
+        let payload = result.unwrap_err();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"first panic"));
+    }
+
+    #[test]
+    fn wrap_with_not_called_on_consume_or_forget() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = AtomicUsize::new(0);
+        let msg = || {
+            calls.fetch_add(1, Ordering::SeqCst);
+            String::from("lazy message")
+        };
+
+        assert_eq!(NoDropMsg::wrap_with(42, msg).consume(), 42);
+        NoDropMsg::wrap_with(42, msg).forget();
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn wrap_with_called_once_on_drop() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = AtomicUsize::new(0);
+        let wrapper = NoDropMsg::wrap_with(42, || {
+            calls.fetch_add(1, Ordering::SeqCst);
+            String::from("lazy message")
+        });
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| drop(wrapper)));
+
+        let payload = result.unwrap_err();
+        assert!(payload.downcast_ref::<String>().unwrap().starts_with("lazy message"));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn wrap_with_panic_during_unwind_is_caught() {
+        let result = std::panic::catch_unwind(|| {
+            let _wrapper = NoDropMsg::wrap_with(42, || panic!("message panicked"));
+            panic!("first panic");
+        });
+
+        let payload = result.unwrap_err();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"first panic"));
+    }
+
+    #[test]
+    fn drop_panic_reports_origin() {
+        let (wrapper, line) = (NoDropMsg::guard("custom message"), line!());
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| drop(wrapper)));
+
+        let payload = result.unwrap_err();
+        let msg = payload.downcast_ref::<String>().unwrap();
+        assert!(msg.starts_with("custom message"), "{msg}");
+        assert!(msg.contains(&format!("(created at {}:{line}:", file!())), "{msg}");
+    }
+
+    #[test]
+    // The captured backtrace is not hashed, so it cannot change the key.
+    #[cfg_attr(feature = "backtrace", allow(clippy::mutable_key_type))]
+    fn lookup_by_borrowed_value() {
+        use std::collections::HashMap;
+
+        let mut map = HashMap::new();
+        map.insert(NoDropMsg::wrap(String::from("key"), "key not consumed"), 42);
+
+        assert_eq!(map.get(&String::from("key")), Some(&42));
+        assert_eq!(map.get(&String::from("other")), None);
+        map.into_keys().for_each(NoDropMsg::forget);
+    }
+
+    #[test]
+    fn comparisons_ignore_msg() {
+        use std::hash::BuildHasher;
+
+        let a = NoDropMsg::wrap(42, "first");
+        let b = NoDropMsg::wrap_with(42, || String::from("second"));
+        assert_eq!(a, b);
+        assert_eq!(a.cmp(&b), Ordering::Equal);
+
+        let hasher = std::hash::RandomState::new();
+        assert_eq!(hasher.hash_one(&a), hasher.hash_one(&b));
+        a.forget();
+        b.forget();
+    }
+
+    #[test]
+    fn without_msg_keeps_value() {
+        assert_eq!(NoDropMsg::wrap(42, "message").without_msg().consume(), 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn without_msg_drop_panics_with_default_msg() {
+        drop(NoDropMsg::wrap(42, "message").without_msg());
+    }
+
+    #[test]
+    fn consume_map_and_into() {
+        assert_eq!(NoDropMsg::wrap(21, "message").consume_map(|n| n * 2), 42);
+        assert_eq!(NoDropMsg::wrap(42_i32, "message").consume_into::<i64>(), 42_i64);
+    }
+
+    #[test]
+    fn inner_accessors() {
+        let mut wrapper = NoDropMsg::wrap(vec![1, 2], "message");
+        wrapper.inner_mut().push(3);
+        assert_eq!(wrapper.inner(), &[1, 2, 3]);
+        assert_eq!(wrapper.consume(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn display_forwards_to_value() {
+        let wrapper = NoDropMsg::wrap("value", "message");
+        assert_eq!(format!("{wrapper}"), "value");
+        wrapper.forget();
+    }
+
+    mod future {
+        use super::*;
+
+        use core::future::Future;
+        use core::pin::pin;
+        use core::task::{Context, Poll, Waker};
+        use std::task::Wake;
+
+        struct NoopWaker;
+
+        impl Wake for NoopWaker {
+            fn wake(self: Arc<Self>) {}
+        }
+
+        /// A future that is pending on its first poll, and ready on its second.
+        #[derive(Default)]
+        struct YieldNow(bool);
+
+        impl Future for YieldNow {
+            type Output = ();
+
+            fn poll(mut self: core::pin::Pin<&mut Self>, _: &mut Context<'_>) -> Poll<()> {
+                match core::mem::replace(&mut self.0, true) {
+                    true => Poll::Ready(()),
+                    false => Poll::Pending,
+                }
+            }
+        }
+
+        fn assert_send_sync<T: Send + Sync>(value: T) -> T {
+            value
+        }
+
+        fn block_on<F: Future>(future: F) -> F::Output {
+            let waker = Waker::from(Arc::new(NoopWaker));
+            let mut context = Context::from_waker(&waker);
+            let mut future = pin!(future);
+            loop {
+                if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                    return output;
+                }
+            }
+        }
+
+        #[test]
+        fn is_send_and_sync() {
+            assert_send_sync(NoDropMsg::wrap(42, "message")).forget();
+            assert_send_sync(NoDropMsg::wrap_with(42, || String::from("message"))).forget();
+        }
+
+        #[test]
+        fn held_across_await() {
+            let future = assert_send_sync(async {
+                let wrapper = NoDropMsg::cancelled_msg(42);
+                YieldNow::default().await;
+                wrapper.consume()
+            });
+            assert_eq!(block_on(future), 42);
+        }
+
+        #[test]
+        #[should_panic(expected = "Future was cancelled before the guarded value was consumed")]
+        fn cancelled_future_panics() {
+            let waker = Waker::from(Arc::new(NoopWaker));
+            let mut future = Box::pin(async {
+                let wrapper = NoDropMsg::cancelled_msg(42);
+                YieldNow::default().await;
+                wrapper.consume()
+            });
+            assert!(future.as_mut().poll(&mut Context::from_waker(&waker)).is_pending());
+            drop(future);
+        }
+    }
+
+    #[test]
+    fn pin_projection() {
+        use core::marker::PhantomPinned;
+
+        let mut wrapper = Box::pin(NoDropMsg::wrap(PhantomPinned, "message"));
+        let _value: Pin<&mut PhantomPinned> = wrapper.as_mut().as_pin_mut();
+        // SAFETY: `PhantomPinned` does not rely on its address.
+        let _value = unsafe { wrapper.consume_pinned_unchecked() };
+    }
+
+    #[test]
+    fn consume_pinned_unpin() {
+        let mut wrapper = Box::pin(NoDropMsg::wrap(41, "message"));
+        *wrapper.as_mut().as_pin_mut() += 1;
+        assert_eq!(wrapper.consume_pinned(), 42);
+    }
 }
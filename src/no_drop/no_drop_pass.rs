@@ -1,6 +1,20 @@
-use std::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    string::String,
+    vec::{IntoIter, Vec},
+};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::hash::{BuildHasher, Hash};
 
-use crate::markers::{Empty, Msg, PassMarker};
+#[cfg(feature = "alloc")]
+use crate::markers::Msg;
+use crate::markers::{Empty, PassMarker};
+
+use super::{NoDropArray, NoDropEmpty};
 
 /// A zero-cost wrapper with no drop checking.
 ///
@@ -10,6 +24,11 @@ use crate::markers::{Empty, Msg, PassMarker};
 ///
 /// The type parameter `M` is a zero-sized marker that distinguishes between
 /// the plain variant ([`Empty`]) and the message variant ([`Msg`]).
+/// It is only held as `fn() -> M`, so it never affects the auto traits or variance of the
+/// wrapper, which match [`NoDropEmpty`](super::NoDropEmpty) and [`NoDropMsg`](super::NoDropMsg).
+///
+/// Every field but the value is zero-sized, so the wrapper is `#[repr(transparent)]`, guaranteeing
+/// it has the same layout and ABI as `T`.
 #[derive(
     Debug,
     PartialEq,
@@ -23,43 +42,369 @@ use crate::markers::{Empty, Msg, PassMarker};
     derive_more::AsRef,
 )]
 #[doc(hidden)]
-#[must_use]
+#[must_use = "the value must be consumed, or it will panic when dropped"]
+#[repr(transparent)]
 pub struct NoDropPass<'msg, M: PassMarker = Empty, T = ()> {
     #[deref]
     #[deref_mut]
     #[as_mut]
     #[as_ref]
     value: T,
-    _lifetime: std::marker::PhantomData<&'msg ()>,
-    _marker: std::marker::PhantomData<M>,
+    /// Covariant in `'msg`, like the message held by [`NoDropMsg`](super::NoDropMsg), so
+    /// substituting one for the other never changes which lifetimes type check.
+    _lifetime: core::marker::PhantomData<&'msg ()>,
+    _marker: core::marker::PhantomData<fn() -> M>,
 }
 
 // Implementation for NoDropPass<Empty, T> (no message variant)
-#[allow(dead_code)]
 impl<T> NoDropPass<'static, Empty, T> {
     /// Creates a new wrapper around `value`.
-    pub fn wrap(value: T) -> Self {
-        Self { value, _lifetime: std::marker::PhantomData, _marker: std::marker::PhantomData }
+    pub const fn wrap(value: T) -> Self {
+        Self { value, _lifetime: core::marker::PhantomData, _marker: core::marker::PhantomData }
     }
 
     /// Consumes the wrapper and returns the inner `T`.
     #[inline]
     #[must_use]
-    pub fn unwrap(self) -> T {
+    pub fn consume(self) -> T {
+        self.value
+    }
+
+    /// Consumes the wrapper and returns the inner `T`, the same as [`Self::consume`].
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> T {
         self.value
     }
 
+    /// Consumes the wrapper, passing the inner `T` to `f` and returning its result.
+    ///
+    /// An alias of [`Self::consume_with_guarded`].
+    #[inline]
+    pub fn consume_map<U>(self, f: impl FnOnce(T) -> U) -> U {
+        self.consume_with_guarded(f)
+    }
+
+    /// Consumes the wrapper, converting the inner `T` into a `U`.
+    #[inline]
+    pub fn consume_into<U: From<T>>(self) -> U {
+        self.value.into()
+    }
+
+    /// Consumes the wrapper, returning an iterator over the inner collection.
+    #[inline]
+    pub fn consume_iter(self) -> T::IntoIter
+    where
+        T: IntoIterator,
+    {
+        self.value.into_iter()
+    }
+
+    /// Consumes the wrapper and returns the inner `T`, discarding `context`.
+    #[inline]
+    #[must_use]
+    pub fn consume_expect(self, _context: &str) -> T {
+        self.value
+    }
+
+    /// Consumes the wrapper and returns the inner `T`.
+    #[deprecated(since = "0.3.0", note = "renamed to `consume`")]
+    #[inline]
+    #[must_use]
+    pub fn unwrap(self) -> T {
+        self.consume()
+    }
+
+    /// Consumes the wrapper, passing the inner `T` to `f` and returning its result.
+    #[inline]
+    #[doc(alias = "consume_map")]
+    pub fn consume_with_guarded<R>(self, f: impl FnOnce(T) -> R) -> R {
+        f(self.value)
+    }
+
+    /// Consumes the wrapper, applying `f` to the inner `T` and re-wrapping the result.
+    #[inline]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> NoDropPass<'static, Empty, U> {
+        NoDropPass::<Empty, U>::wrap(f(self.value))
+    }
+
+    /// Consumes the wrapper, passing the inner `T` to `next`, which produces the next wrapper.
+    #[inline]
+    #[doc(alias = "and_then")]
+    pub fn then<U>(self, next: impl FnOnce(T) -> NoDropPass<'static, Empty, U>) -> NoDropPass<'static, Empty, U> {
+        next(self.value)
+    }
+
+    /// Consumes the wrapper, passing the inner `T` to a fallible `finalize`, re-wrapping the value
+    /// on failure.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error produced by `finalize`, paired with the re-wrapped value.
+    pub fn consume_try<R, E>(self, finalize: impl FnOnce(T) -> Result<R, (E, T)>) -> Result<R, (E, Self)> {
+        finalize(self.value).map_err(|(err, value)| (err, Self::wrap(value)))
+    }
+
+    /// Replaces the inner `T` with `value`, returning the old value.
+    #[must_use]
+    pub fn replace(this: &mut Self, value: T) -> T {
+        core::mem::replace(&mut this.value, value)
+    }
+
+    /// Swaps the inner values of two wrappers.
+    pub fn swap(this: &mut Self, other: &mut Self) {
+        core::mem::swap(&mut this.value, &mut other.value);
+    }
+
+    /// Returns the wrapper holding the greater value, forgetting the other.
+    pub fn max_consume(self, other: Self) -> Self
+    where
+        T: Ord,
+    {
+        match self.value > other.value {
+            true => self,
+            false => other,
+        }
+    }
+
+    /// Returns the wrapper holding the lesser value, forgetting the other.
+    pub fn min_consume(self, other: Self) -> Self
+    where
+        T: Ord,
+    {
+        match self.value > other.value {
+            true => other,
+            false => self,
+        }
+    }
+
+    /// Consumes the wrapper, moving the inner `T` into a message wrapper, discarding `msg`.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn with_msg<'msg, M: Into<Cow<'msg, str>>>(self, msg: M) -> NoDropPass<'msg, Msg, T> {
+        NoDropPass::<Msg, T>::wrap(self.value, msg)
+    }
+
     /// Forgets this guard, safely dropping it.
     pub fn forget(self) {
         drop(self);
     }
+
+    /// Forgets this guard, the same as [`Self::forget`].
+    pub fn debug_forget(self) {
+        self.forget();
+    }
+}
+
+impl<T> NoDropPass<'static, Empty, core::mem::MaybeUninit<T>> {
+    /// Creates a new wrapper around an uninitialized `T`.
+    pub const fn uninit() -> Self {
+        Self::wrap(core::mem::MaybeUninit::uninit())
+    }
+
+    /// Returns a raw pointer to the wrapped buffer, for it to be initialized through.
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
+        self.value.as_mut_ptr()
+    }
+
+    /// Transitions the wrapper from an uninitialized buffer to the initialized `T`.
+    ///
+    /// # Safety
+    ///
+    /// The buffer must be fully initialized to a valid `T`, exactly as required by
+    /// [`MaybeUninit::assume_init`](core::mem::MaybeUninit::assume_init).
+    pub unsafe fn assume_init(self) -> NoDropPass<'static, Empty, T> {
+        // SAFETY: The caller guarantees the buffer is initialized.
+        NoDropPass::<Empty, T>::wrap(unsafe { self.value.assume_init() })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> NoDropPass<'static, Empty, Vec<T>> {
+    /// Consumes the wrapper, returning an iterator over the owned elements.
+    pub fn into_iter_consuming(self) -> IntoIter<T> {
+        self.value.into_iter()
+    }
+
+    /// Returns an iterator over the elements of the wrapped vector, paired with their indices.
+    pub fn iter_enumerate(&self) -> impl Iterator<Item = (usize, &T)> + '_ {
+        self.value.iter().enumerate()
+    }
+
+    /// Consumes the wrapper, folding every element into an accumulator with `f`.
+    pub fn fold<B>(self, init: B, f: impl FnMut(B, T) -> B) -> B {
+        self.value.into_iter().fold(init, f)
+    }
+
+    /// Returns `true` if any element of the wrapped vector satisfies `pred`.
+    pub fn any(&self, pred: impl FnMut(&T) -> bool) -> bool {
+        self.value.iter().any(pred)
+    }
+
+    /// Returns `true` if every element of the wrapped vector satisfies `pred`.
+    pub fn all(&self, pred: impl FnMut(&T) -> bool) -> bool {
+        self.value.iter().all(pred)
+    }
+
+    /// Returns the number of elements of the wrapped vector that satisfy `pred`.
+    pub fn count_if(&self, mut pred: impl FnMut(&T) -> bool) -> usize {
+        self.value.iter().filter(|element| pred(element)).count()
+    }
+
+    /// Returns the index of the first element of the wrapped vector that satisfies `pred`.
+    pub fn position(&self, pred: impl FnMut(&T) -> bool) -> Option<usize> {
+        self.value.iter().position(pred)
+    }
+
+    /// Consumes the wrapper, splitting the vector into `size` element chunks, each in its own
+    /// wrapper. The last chunk may be shorter.
+    ///
+    /// # Panics
+    ///
+    /// If `size` is zero.
+    #[track_caller]
+    pub fn into_chunks(self, size: usize) -> impl DoubleEndedIterator<Item = NoDropPass<'static, Empty, Vec<T>>> {
+        let mut values = self.value;
+        assert!(size != 0, "chunk size must be non-zero");
+        let mut chunks = Vec::with_capacity(values.len().div_ceil(size));
+        for start in (0..values.len()).step_by(size).rev() {
+            chunks.push(NoDropPass::<Empty, Vec<T>>::wrap(values.split_off(start)));
+        }
+        chunks.into_iter().rev()
+    }
+
+    /// Consumes the wrapper, splitting off the first element, and returning it along with a new
+    /// wrapper over the rest.
+    ///
+    /// # Errors
+    ///
+    /// If the vector is empty, returning the original wrapper.
+    pub fn guarded_split_first(self) -> Result<(T, Self), Self> {
+        if self.value.is_empty() {
+            return Err(self);
+        }
+        let mut values = self.value;
+        let first = values.remove(0);
+        Ok((first, Self::wrap(values)))
+    }
+
+    /// Replaces the elements in `range` with `replacement`, returning the removed elements.
+    ///
+    /// # Panics
+    ///
+    /// If `range` is out of bounds.
+    pub fn splice_range(&mut self, range: core::ops::Range<usize>, replacement: impl IntoIterator<Item = T>) -> Vec<T> {
+        self.value.splice(range, replacement).collect()
+    }
+
+    /// Returns a reference to the element at `index`, or [`None`] if out of bounds.
+    #[must_use]
+    pub fn guarded_get(&self, index: usize) -> Option<&T> {
+        self.value.get(index)
+    }
+
+    /// Returns a mutable reference to the element at `index`, or [`None`] if out of bounds.
+    #[must_use]
+    pub fn guarded_get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.value.get_mut(index)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Ord> NoDropPass<'static, Empty, Vec<T>> {
+    /// Sorts the wrapped vector, then removes consecutive duplicates.
+    pub fn sort_dedup(&mut self) {
+        self.value.sort();
+        self.value.dedup();
+    }
+
+    /// Returns the smallest element of the wrapped vector, or [`None`] if it is empty.
+    pub fn guarded_min(&self) -> Option<&T> {
+        self.value.iter().min()
+    }
+
+    /// Returns the largest element of the wrapped vector, or [`None`] if it is empty.
+    pub fn guarded_max(&self) -> Option<&T> {
+        self.value.iter().max()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> NoDropPass<'static, Empty, Vec<Vec<T>>> {
+    /// Consumes the wrapper, flattening the nested vectors into a new wrapper.
+    pub fn guarded_concat(self) -> NoDropPass<'static, Empty, Vec<T>> {
+        self.map(|nested| nested.into_iter().flatten().collect())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> NoDropPass<'static, Empty, Vec<T>> {
+    /// Consumes the wrapper, grouping the vector's elements by the key `f` returns for each, into a
+    /// new wrapper around the map. Elements keep their relative order within each group.
+    pub fn group_by_key<K: Eq + Hash>(
+        self,
+        mut f: impl FnMut(&T) -> K,
+    ) -> NoDropPass<'static, Empty, HashMap<K, Vec<T>>> {
+        self.map(|values| {
+            let mut groups: HashMap<K, Vec<T>> = HashMap::new();
+            for value in values {
+                groups.entry(f(&value)).or_default().push(value);
+            }
+            groups
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V, S: BuildHasher> NoDropPass<'static, Empty, HashMap<K, V, S>> {
+    /// Consumes the wrapper, passing each value of the map through `f`, into a new wrapper around a
+    /// map with the same keys.
+    pub fn map_values<W>(self, mut f: impl FnMut(V) -> W) -> NoDropPass<'static, Empty, HashMap<K, W, S>>
+    where
+        S: Default,
+    {
+        self.map(|map| map.into_iter().map(|(key, value)| (key, f(value))).collect())
+    }
+
+    /// Inserts every entry of `iter` into the wrapped map, replacing the values of existing keys.
+    pub fn extend_entries(&mut self, iter: impl IntoIterator<Item = (K, V)>) {
+        self.value.extend(iter);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'f, A, R> NoDropPass<'static, Empty, Box<dyn FnMut(A) -> R + 'f>> {
+    /// Calls the wrapped callback with `arg`.
+    pub fn call(&mut self, arg: A) -> R {
+        (self.value)(arg)
+    }
+
+    /// Consumes the wrapper, calling the callback one final time with `arg`.
+    pub fn run_final(mut self, arg: A) -> R {
+        (self.value)(arg)
+    }
+}
+
+impl<T, const N: usize> NoDropPass<'static, Empty, [T; N]> {
+    /// Converts the wrapper into a [`NoDropArray`], whose elements can be taken out one at a time.
+    ///
+    /// Like this wrapper, the new one never panics when dropped.
+    #[track_caller]
+    pub fn into_elements(self) -> NoDropArray<T, N> {
+        NoDropArray::wrap_inert(self.value)
+    }
+}
+
+/// Unwraps each wrapper in `wrappers`, in order, passing its index and value to `f`.
+#[cfg(feature = "alloc")]
+pub fn consume_indexed_pass<T>(wrappers: Vec<NoDropPass<'static, Empty, T>>, mut f: impl FnMut(usize, T)) {
+    wrappers.into_iter().map(|wrapper| wrapper.value).enumerate().for_each(|(index, value)| f(index, value));
 }
 
-#[allow(dead_code)]
 impl NoDropPass<'static, Empty, ()> {
     /// Creates a new empty guard.
     pub const fn new() -> Self {
-        Self { value: (), _lifetime: std::marker::PhantomData, _marker: std::marker::PhantomData }
+        Self { value: (), _lifetime: core::marker::PhantomData, _marker: core::marker::PhantomData }
     }
 }
 
@@ -69,48 +414,328 @@ impl Default for NoDropPass<'static, Empty, ()> {
     }
 }
 
-impl Clone for NoDropPass<'static, Empty, ()> {
+impl<T: Clone> Clone for NoDropPass<'static, Empty, T> {
     fn clone(&self) -> Self {
-        Self::new()
+        Self::wrap(self.value.clone())
     }
 }
 
 // Implementation for NoDropPass<Msg, T> (message variant)
-#[allow(dead_code)]
+#[cfg(feature = "alloc")]
 impl<'msg, T> NoDropPass<'msg, Msg, T> {
     /// Creates a new wrapper around `value` with a custom panic message.
     ///
     /// The message is immediately dropped and ignored, since this type never [`panic!`]s.
     pub fn wrap<M: Into<Cow<'msg, str>>>(value: T, _msg: M) -> Self {
-        Self { value, _lifetime: std::marker::PhantomData, _marker: std::marker::PhantomData }
+        Self { value, _lifetime: core::marker::PhantomData, _marker: core::marker::PhantomData }
+    }
+
+    /// Creates a new wrapper around `value` with a lazily computed panic message.
+    ///
+    /// The message function is immediately dropped without being called, since this type never
+    /// [`panic!`]s.
+    pub fn wrap_with<F: Fn() -> String + Send + Sync + core::panic::RefUnwindSafe + 'msg>(value: T, _f: F) -> Self {
+        Self { value, _lifetime: core::marker::PhantomData, _marker: core::marker::PhantomData }
+    }
+
+    /// Creates a new wrapper around `value` with the future cancellation panic message.
+    ///
+    /// The message is ignored, since this type never [`panic!`]s.
+    pub fn cancelled_msg(value: T) -> Self {
+        Self::wrap(value, "")
     }
 
     /// Consumes the wrapper and returns the inner `T`.
     #[inline]
     #[must_use]
-    pub fn unwrap(self) -> T {
+    pub fn consume(self) -> T {
+        self.value
+    }
+
+    /// Consumes the wrapper and returns the inner `T`, the same as [`Self::consume`].
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> T {
         self.value
     }
 
+    /// Consumes the wrapper, passing the inner `T` to `f` and returning its result.
+    #[inline]
+    pub fn consume_map<U>(self, f: impl FnOnce(T) -> U) -> U {
+        f(self.value)
+    }
+
+    /// Consumes the wrapper, converting the inner `T` into a `U`.
+    #[inline]
+    pub fn consume_into<U: From<T>>(self) -> U {
+        self.value.into()
+    }
+
+    /// Consumes the wrapper and returns the inner `T`.
+    #[deprecated(since = "0.3.0", note = "renamed to `consume`")]
+    #[inline]
+    #[must_use]
+    pub fn unwrap(self) -> T {
+        self.consume()
+    }
+
+    /// Consumes the wrapper, applying `f` to the inner `T` and re-wrapping the result.
+    #[inline]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> NoDropPass<'msg, Msg, U> {
+        NoDropPass::<Msg, U>::wrap(f(self.value), "")
+    }
+
+    /// Consumes the wrapper, moving the inner `T` into a plain wrapper.
+    #[inline]
+    pub fn without_msg(self) -> NoDropPass<'static, Empty, T> {
+        NoDropPass::<Empty, T>::wrap(self.value)
+    }
+
+    /// Rewrites the panic message with `f`.
+    ///
+    /// Since no message is retained, `f` is never called.
+    #[inline]
+    pub fn map_msg(self, _f: impl FnOnce(Cow<'msg, str>) -> Cow<'msg, str>) -> Self {
+        self
+    }
+
+    /// Replaces the inner `T` with `value`, returning the old value.
+    #[must_use]
+    pub fn replace(this: &mut Self, value: T) -> T {
+        core::mem::replace(&mut this.value, value)
+    }
+
+    /// Swaps the inner values of two wrappers.
+    pub fn swap(this: &mut Self, other: &mut Self) {
+        core::mem::swap(&mut this.value, &mut other.value);
+    }
+
+    /// Returns the panic message.
+    ///
+    /// Since no message is retained, this is always empty. The signature matches
+    /// [`NoDropMsg::msg`](super::NoDropMsg::msg), so code reading the message compiles in every
+    /// build.
+    #[must_use]
+    pub fn msg(&self) -> Cow<'_, str> {
+        Cow::Borrowed("")
+    }
+
+    /// Replaces the panic message with `msg`.
+    ///
+    /// Since no message is retained, `msg` is discarded.
+    #[inline]
+    pub fn set_msg<M: Into<Cow<'msg, str>>>(&mut self, _msg: M) {}
+
+    /// Appends `more` to the panic message.
+    ///
+    /// Since no message is retained, `more` is discarded.
+    #[inline]
+    pub fn context(self, _more: impl core::fmt::Display) -> Self {
+        self
+    }
+
     /// Forgets this guard, safely dropping it.
     pub fn forget(self) {
         drop(self);
     }
+
+    /// Forgets this guard, the same as [`Self::forget`].
+    pub fn debug_forget(self) {
+        self.forget();
+    }
 }
 
-#[allow(dead_code)]
+#[cfg(feature = "alloc")]
 impl<'msg> NoDropPass<'msg, Msg, ()> {
     /// Creates a new empty no drop guard, with a custom panic message.
     ///
     /// The message is immediately dropped and ignored, since this type never [`panic!`]s.
     pub fn guard<M: Into<Cow<'msg, str>>>(_msg: M) -> Self {
-        Self { value: (), _lifetime: std::marker::PhantomData, _marker: std::marker::PhantomData }
+        Self { value: (), _lifetime: core::marker::PhantomData, _marker: core::marker::PhantomData }
+    }
+
+    /// Creates a new empty no drop guard, with a formatted panic message.
+    ///
+    /// The arguments are ignored, since this type never [`panic!`]s.
+    pub fn guard_fmt(_args: core::fmt::Arguments<'_>) -> Self {
+        Self { value: (), _lifetime: core::marker::PhantomData, _marker: core::marker::PhantomData }
     }
 }
 
+#[cfg(feature = "alloc")]
+impl Default for NoDropPass<'_, Msg, ()> {
+    fn default() -> Self {
+        Self::guard("")
+    }
+}
+
+#[cfg(feature = "alloc")]
 impl<'msg> Clone for NoDropPass<'msg, Msg, ()> {
     fn clone(&self) -> Self {
-        Self { value: (), _lifetime: std::marker::PhantomData, _marker: std::marker::PhantomData }
+        Self { value: (), _lifetime: core::marker::PhantomData, _marker: core::marker::PhantomData }
+    }
+}
+
+// Shared implementation for both variants
+impl<M: PassMarker, T> NoDropPass<'_, M, T> {
+    /// Returns a reference to the inner `T`.
+    #[must_use]
+    pub const fn inner(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns a mutable reference to the inner `T`.
+    #[must_use]
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+/// Pin projection, matching [`NoDropEmpty`](super::NoDropEmpty) and [`NoDropMsg`](super::NoDropMsg).
+///
+/// The value is structurally pinned, since the wrapper never moves it while pinned.
+impl<M: PassMarker, T> NoDropPass<'_, M, T> {
+    /// Projects a pinned reference to the wrapper to a pinned reference to its value.
+    pub fn as_pin_ref(self: core::pin::Pin<&Self>) -> core::pin::Pin<&T> {
+        // SAFETY: The value is structurally pinned, see above.
+        unsafe { self.map_unchecked(|this| &this.value) }
+    }
+
+    /// Projects a pinned mutable reference to the wrapper to a pinned mutable reference to its
+    /// value.
+    pub fn as_pin_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut T> {
+        // SAFETY: The value is structurally pinned, see above.
+        unsafe { self.map_unchecked_mut(|this| &mut this.value) }
+    }
+
+    /// Consumes a pinned, boxed, wrapper and returns the inner `T`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn consume_pinned(self: core::pin::Pin<alloc::boxed::Box<Self>>) -> T
+    where
+        T: Unpin,
+    {
+        core::pin::Pin::into_inner(self).value
+    }
+
+    /// Consumes a pinned, boxed, wrapper and returns the inner `T`, moving it out of the pin.
+    ///
+    /// # Safety
+    ///
+    /// Moving the value must not violate its pinning guarantees.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub unsafe fn consume_pinned_unchecked(self: core::pin::Pin<alloc::boxed::Box<Self>>) -> T {
+        // SAFETY: The caller guarantees the value may be moved.
+        unsafe { core::pin::Pin::into_inner_unchecked(self) }.value
+    }
+}
+
+impl<M: PassMarker, T> core::borrow::Borrow<T> for NoDropPass<'_, M, T> {
+    fn borrow(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<M: PassMarker, T> core::borrow::BorrowMut<T> for NoDropPass<'_, M, T> {
+    fn borrow_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T> From<T> for NoDropPass<'static, Empty, T> {
+    fn from(value: T) -> Self {
+        Self::wrap(value)
+    }
+}
+
+/// Consumes the [`panic!`]ing guard, so it is never dropped armed.
+impl<T> From<NoDropEmpty<T>> for NoDropPass<'static, Empty, T> {
+    fn from(guard: NoDropEmpty<T>) -> Self {
+        Self::wrap(guard.consume())
+    }
+}
+
+/// The new guard reports the conversion as its creation location.
+impl<T> From<NoDropPass<'static, Empty, T>> for NoDropEmpty<T> {
+    #[track_caller]
+    fn from(pass: NoDropPass<'static, Empty, T>) -> Self {
+        Self::wrap(pass.consume())
+    }
+}
+
+/// The message is always empty, since this type discards it.
+#[cfg(feature = "alloc")]
+impl<T> From<NoDropPass<'_, Msg, T>> for (T, String) {
+    fn from(no_drop: NoDropPass<'_, Msg, T>) -> Self {
+        (no_drop.value, String::new())
+    }
+}
+
+impl<M: PassMarker, T: core::fmt::Display> core::fmt::Display for NoDropPass<'_, M, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<M: PassMarker, T: core::fmt::Pointer> core::fmt::Pointer for NoDropPass<'_, M, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+/// `serde` implementations matching the shape of [`NoDropEmpty`](super::NoDropEmpty) and
+/// [`NoDropMsg`](super::NoDropMsg), so that data round-trips across the `dbg` substitution.
+///
+/// Since the message variant discards its message, it serializes an empty `msg`, and ignores any
+/// `msg` when deserializing.
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::*;
+
+    impl<T: Serialize> Serialize for NoDropPass<'static, Empty, T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.value.serialize(serializer)
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for NoDropPass<'static, Empty, T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            T::deserialize(deserializer).map(Self::wrap)
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[derive(Serialize)]
+    #[serde(rename = "NoDropMsg")]
+    struct MsgSer<'a, T> {
+        value: &'a T,
+        msg: &'static str,
+    }
+
+    #[cfg(feature = "alloc")]
+    #[derive(Deserialize)]
+    #[serde(rename = "NoDropMsg")]
+    struct MsgDe<T> {
+        value: T,
+        #[serde(rename = "msg", default)]
+        _msg: serde::de::IgnoredAny,
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<T: Serialize> Serialize for NoDropPass<'_, Msg, T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            MsgSer { value: &self.value, msg: "" }.serialize(serializer)
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for NoDropPass<'_, Msg, T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            MsgDe::deserialize(deserializer).map(|repr| Self::wrap(repr.value, ""))
+        }
     }
 }
 
@@ -128,6 +753,40 @@ mod tests {
 
     test_clone!(passthrough_empty_clone, NoDropPass<'static, Empty, ()>, NoDropPass::<Empty, ()>::new, ());
 
+    #[test]
+    fn passthrough_inner_accessors() {
+        let mut wrapper = NoDropPass::<Empty, _>::wrap(41);
+        *wrapper.inner_mut() += 1;
+        assert_eq!(*wrapper.inner(), 42);
+        assert_eq!(wrapper.consume(), 42);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn passthrough_msg_round_trip() {
+        let wrapper = NoDropPass::<Empty, _>::wrap(42).with_msg("message");
+        assert_eq!(wrapper.without_msg().consume(), 42);
+    }
+
+    #[test]
+    fn passthrough_into_inner_round_trips() {
+        let wrapper: NoDropPass<Empty, _> = 42.into();
+        assert_eq!(wrapper.into_inner(), 42);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn passthrough_into_value_and_msg() {
+        let (value, msg) = NoDropPass::<Msg, _>::wrap(42, "message").into();
+        assert_eq!((value, msg), (42, String::new()));
+    }
+
+    #[test]
+    fn passthrough_consume_iter() {
+        let wrapper = NoDropPass::<Empty, _>::wrap(vec![1, 2, 3]);
+        assert_eq!(wrapper.consume_iter().sum::<i32>(), 6);
+    }
+
     #[test]
     fn passthrough_empty_drop_no_panic() {
         let wrapper = NoDropPass::<Empty, _>::wrap(42);
@@ -135,23 +794,112 @@ mod tests {
     }
 
     // Tests for Msg variant
+    #[cfg(feature = "alloc")]
     test_ctor!(passthrough_msg_wrap_consume, NoDropPass::<Msg, _>::wrap, (42, "message"), 42);
+    #[cfg(feature = "alloc")]
     test_ctor!(passthrough_msg_guard, NoDropPass::<Msg, ()>::guard, ("expected message"), ());
 
+    #[cfg(feature = "alloc")]
     test_forget!(passthrough_msg_forget, NoDropPass::<Msg, _>::wrap, (42, "message"));
 
+    #[cfg(feature = "alloc")]
     test_clone!(passthrough_msg_clone, NoDropPass<'static, Msg, ()>, NoDropPass::<Msg, ()>::guard, ("message"));
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn passthrough_msg_drop_no_panic() {
         let wrapper = NoDropPass::<Msg, _>::wrap(42, "should not panic");
         drop(wrapper); // No panic
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn passthrough_msg_borrowed() {
         let msg = String::from("borrowed message");
         let wrapper = NoDropPass::<Msg, _>::wrap(42, msg.as_str());
-        assert_eq!(wrapper.unwrap(), 42);
+        assert_eq!(wrapper.consume(), 42);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn passthrough_empty_serde_round_trip() {
+        let json = serde_json::to_string(&NoDropPass::<Empty, _>::wrap(42)).unwrap();
+        assert_eq!(json, "42");
+
+        let wrapper: NoDropPass<Empty, i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(wrapper.consume(), 42);
+    }
+
+    #[cfg(all(feature = "serde", feature = "alloc"))]
+    #[test]
+    fn passthrough_msg_serde_round_trip() {
+        let json = serde_json::to_string(&NoDropPass::<Msg, _>::wrap(42, "message")).unwrap();
+        assert_eq!(json, r#"{"value":42,"msg":""}"#);
+
+        let wrapper: NoDropPass<Msg, i32> = serde_json::from_str(r#"{"value":42,"msg":"message"}"#).unwrap();
+        assert_eq!(wrapper.consume(), 42);
+    }
+
+    #[test]
+    fn passthrough_is_zero_cost() {
+        assert_eq!(size_of::<NoDropPass<Empty, u32>>(), size_of::<u32>());
+        #[cfg(feature = "alloc")]
+        assert_eq!(size_of::<NoDropPass<Msg, u32>>(), size_of::<u32>());
+    }
+
+    #[test]
+    fn passthrough_is_transparent() {
+        use core::mem::{align_of, transmute};
+
+        assert_eq!(align_of::<NoDropPass<Empty, u64>>(), align_of::<u64>());
+        assert_eq!(size_of::<NoDropPass<Empty, [u8; 3]>>(), size_of::<[u8; 3]>());
+        assert_eq!(size_of::<NoDropPass<Empty, Option<&u8>>>(), size_of::<&u8>());
+
+        // SAFETY: The wrapper is `#[repr(transparent)]` over its value.
+        let value: u64 = unsafe { transmute(NoDropPass::<Empty, u64>::wrap(42)) };
+        assert_eq!(value, 42);
+        #[cfg(feature = "alloc")]
+        {
+            // SAFETY: The wrapper is `#[repr(transparent)]` over its value.
+            let value: u64 = unsafe { transmute(NoDropPass::<Msg, u64>::wrap(42, "message")) };
+            assert_eq!(value, 42);
+        }
+    }
+
+    // Both are covariant in `'msg`, so the `dbg` alias accepts the same lifetimes in every build.
+    #[cfg(feature = "alloc")]
+    #[allow(dead_code)]
+    fn passthrough_is_covariant<'a>(value: NoDropPass<'static, Msg, &'static str>) -> NoDropPass<'a, Msg, &'a str> {
+        value
+    }
+
+    #[cfg(feature = "alloc")]
+    #[allow(dead_code)]
+    fn msg_is_covariant<'a>(
+        value: crate::no_drop::NoDropMsg<'static, &'static str>,
+    ) -> crate::no_drop::NoDropMsg<'a, &'a str> {
+        value
+    }
+
+    #[test]
+    fn display_forwards_to_value() {
+        assert_eq!(format!("{}", NoDropPass::<Empty, _>::wrap(42)), "42");
+        #[cfg(feature = "alloc")]
+        assert_eq!(format!("{}", NoDropPass::<Msg, _>::wrap("value", "message")), "value");
+    }
+
+    #[test]
+    fn round_trip_through_checked_guard() {
+        let pass: NoDropPass<Empty, i32> = NoDropEmpty::wrap(42).into();
+        let guard: NoDropEmpty<i32> = pass.into();
+        let pass = NoDropPass::<Empty, i32>::from(guard);
+        assert_eq!(pass.consume(), 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn converted_checked_guard_is_armed() {
+        let guard: NoDropEmpty<i32> = NoDropPass::<Empty, _>::wrap(42).into();
+        drop(guard);
     }
 }
@@ -0,0 +1,115 @@
+use core::fmt::Debug;
+use core::ops::Deref;
+
+use super::NoDropMsg;
+
+pub const UNHANDLED_RESULT_PANIC_MSG: &str = "unhandled Result dropped";
+
+/// A wrapper around a [`Result`] that [`panic!`]s if dropped without being [`Self::handle`]d.
+///
+/// This enforces at runtime that an error is explicitly dealt with, rather than just warned about
+/// by `#[must_use]`. Dropping the wrapper [`panic!`]s with [`UNHANDLED_RESULT_PANIC_MSG`], and the
+/// location the wrapper was created at. The [`Result`] can be inspected through [`Deref`] without
+/// handling it.
+///
+/// # Examples
+///
+/// ```rust
+/// use no_drop::rls::NoDropResult;
+///
+/// let result = NoDropResult::wrap("42".parse::<i32>());
+/// assert!(result.is_ok());
+/// assert_eq!(result.unwrap_ok(), 42);
+/// ```
+#[derive(Debug)]
+#[must_use = "the result must be handled, or it will panic when dropped"]
+pub struct NoDropResult<T, E>(NoDropMsg<'static, Result<T, E>>);
+
+impl<T, E> NoDropResult<T, E> {
+    /// Creates a new wrapper around `result`.
+    #[inline]
+    #[track_caller]
+    pub fn wrap(result: Result<T, E>) -> Self {
+        Self(NoDropMsg::wrap(result, UNHANDLED_RESULT_PANIC_MSG))
+    }
+
+    /// Consumes the wrapper, returning the [`Result`] to be handled.
+    ///
+    /// # Errors
+    ///
+    /// Returns the wrapped error, if any.
+    #[inline]
+    pub fn handle(self) -> Result<T, E> {
+        self.0.consume()
+    }
+
+    /// Consumes the wrapper, returning the [`Ok`] value.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the [`Debug`] representation of the error, if the result is an [`Err`].
+    #[inline]
+    #[track_caller]
+    pub fn unwrap_ok(self) -> T
+    where
+        E: Debug,
+    {
+        match self.handle() {
+            Ok(value) => value,
+            Err(err) => panic!("called `unwrap_ok` on an `Err` value: {err:?}"),
+        }
+    }
+}
+
+impl<T, E> Deref for NoDropResult<T, E> {
+    type Target = Result<T, E>;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.inner()
+    }
+}
+
+impl<T, E> From<Result<T, E>> for NoDropResult<T, E> {
+    #[track_caller]
+    fn from(result: Result<T, E>) -> Self {
+        Self::wrap(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handle_ok() {
+        let result: NoDropResult<i32, &str> = NoDropResult::wrap(Ok(42));
+        assert_eq!(result.handle(), Ok(42));
+    }
+
+    #[test]
+    fn handle_err() {
+        let result: NoDropResult<i32, &str> = NoDropResult::from(Err("failed"));
+        assert!(result.is_err());
+        assert_eq!(result.handle(), Err("failed"));
+    }
+
+    #[test]
+    fn unwrap_ok_ok() {
+        let result: NoDropResult<i32, &str> = NoDropResult::wrap(Ok(42));
+        assert_eq!(result.unwrap_ok(), 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "called `unwrap_ok` on an `Err` value: \"failed\"")]
+    fn unwrap_ok_err_panics() {
+        let result: NoDropResult<i32, &str> = NoDropResult::wrap(Err("failed"));
+        let _ = result.unwrap_ok();
+    }
+
+    #[test]
+    #[should_panic(expected = "unhandled Result dropped")]
+    fn drop_unhandled_panics() {
+        let result: NoDropResult<i32, &str> = NoDropResult::wrap(Ok(42));
+        drop(result);
+    }
+}
@@ -0,0 +1,96 @@
+use super::NoDropEmpty;
+
+macro_rules! no_drop_tuple {
+    ($name:ident, $count:literal, $($value:ident: $ty:ident [$index:tt] $get:ident $get_mut:ident),+) => {
+        #[doc = concat!("A wrapper around ", $count, " values that share one obligation, and always [`panic!`]s if")]
+        /// dropped without being [`Self::consume`]d or [`Self::forget`]ten.
+        ///
+        /// Unlike a [`NoDrop`](super::NoDropEmpty) of a tuple, each value has its own accessor. The
+        /// location the wrapper was created at is reported in the [`panic!`] message.
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[must_use]
+        pub struct $name<$($ty),+>(NoDropEmpty<($($ty,)+)>);
+
+        impl<$($ty),+> $name<$($ty),+> {
+            /// Creates a new wrapper around the values.
+            #[track_caller]
+            pub const fn wrap($($value: $ty),+) -> Self {
+                Self(NoDropEmpty::wrap(($($value,)+)))
+            }
+
+            /// Consumes the wrapper and returns the values.
+            #[inline]
+            #[must_use]
+            pub fn consume(self) -> ($($ty,)+) {
+                self.0.consume()
+            }
+
+            /// Forgets this guard, safely dropping the values.
+            #[inline]
+            pub fn forget(self) {
+                self.0.forget();
+            }
+
+            $(
+                #[doc = concat!("Returns a reference to the value at index ", stringify!($index), ".")]
+                #[inline]
+                #[must_use]
+                pub const fn $get(&self) -> &$ty {
+                    &self.0.inner().$index
+                }
+
+                #[doc = concat!("Returns a mutable reference to the value at index ", stringify!($index), ".")]
+                #[inline]
+                #[must_use]
+                pub fn $get_mut(&mut self) -> &mut $ty {
+                    &mut self.0.inner_mut().$index
+                }
+            )+
+        }
+    };
+}
+
+no_drop_tuple!(NoDrop2, "two", a: A [0] get_0 get_0_mut, b: B [1] get_1 get_1_mut);
+no_drop_tuple!(NoDrop3, "three", a: A [0] get_0 get_0_mut, b: B [1] get_1 get_1_mut, c: C [2] get_2 get_2_mut);
+no_drop_tuple!(
+    NoDrop4,
+    "four",
+    a: A [0] get_0 get_0_mut,
+    b: B [1] get_1 get_1_mut,
+    c: C [2] get_2 get_2_mut,
+    d: D [3] get_3 get_3_mut
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consume_returns_values() {
+        assert_eq!(NoDrop2::wrap(1, "two").consume(), (1, "two"));
+        assert_eq!(NoDrop3::wrap(1, 2, 3).consume(), (1, 2, 3));
+        assert_eq!(NoDrop4::wrap(1, 2, 3, 4).consume(), (1, 2, 3, 4));
+    }
+
+    #[test]
+    fn accessors() {
+        let mut pair = NoDrop2::wrap(1, 'b');
+        assert_eq!(*pair.get_0(), 1);
+        assert_eq!(*pair.get_1(), 'b');
+
+        *pair.get_0_mut() += 1;
+        *pair.get_1_mut() = 'c';
+        assert_eq!(pair.consume(), (2, 'c'));
+    }
+
+    #[test]
+    fn forget() {
+        NoDrop4::wrap(1, 2, 3, 4).forget();
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn drop_panics() {
+        drop(NoDrop2::wrap(1, 2));
+    }
+}
@@ -0,0 +1,752 @@
+use alloc::boxed::Box;
+use alloc::vec::{IntoIter, Vec};
+use core::ops::Range;
+use core::slice::{ChunksMut, Iter, IterMut};
+
+use super::NoDropEmpty;
+
+/// Forwarding methods for guarded [`Vec`]s.
+///
+/// All methods leave the guard armed, unless noted otherwise.
+impl<T> NoDropEmpty<Vec<T>> {
+    /// Returns an iterator over `size` element mutable chunks of the guarded vector.
+    ///
+    /// See [`slice::chunks_mut`] for details.
+    ///
+    /// # Panics
+    ///
+    /// If `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let mut wrapper = NoDrop::wrap(vec![1, 2, 3, 4, 5]);
+    /// wrapper.chunks_mut(2).for_each(|chunk| chunk.reverse());
+    /// assert_eq!(wrapper.consume(), vec![2, 1, 4, 3, 5]);
+    /// ```
+    pub fn chunks_mut(&mut self, size: usize) -> ChunksMut<'_, T> {
+        <[T]>::chunks_mut(self, size)
+    }
+
+    /// Removes and returns the element at `index`, replacing it with the last element.
+    ///
+    /// The returned element is no longer guarded. See [`Vec::swap_remove`] for details.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        Vec::swap_remove(self, index)
+    }
+
+    /// Returns a reference to the element at `index`, or [`None`] if out of bounds.
+    ///
+    /// Unlike [`slice::get`], which is still reached through `Deref`, this only takes an `index`.
+    #[must_use]
+    pub fn guarded_get(&self, index: usize) -> Option<&T> {
+        <[T]>::get(self, index)
+    }
+
+    /// Returns a mutable reference to the element at `index`, or [`None`] if out of bounds.
+    #[must_use]
+    pub fn guarded_get_mut(&mut self, index: usize) -> Option<&mut T> {
+        <[T]>::get_mut(self, index)
+    }
+
+    /// Splits the guarded vector into `N` element arrays, starting at the beginning, and a
+    /// remainder shorter than `N`.
+    ///
+    /// See [`slice::as_chunks`] for details.
+    ///
+    /// # Panics
+    ///
+    /// If `N` is zero.
+    #[must_use]
+    pub fn as_chunks<const N: usize>(&self) -> (&[[T; N]], &[T]) {
+        <[T]>::as_chunks(self)
+    }
+
+    /// Replaces the elements in `range` with `replacement`, returning the removed elements.
+    ///
+    /// The returned elements are no longer guarded. See [`Vec::splice`] for details.
+    ///
+    /// # Panics
+    ///
+    /// If `range` is out of bounds.
+    pub fn splice_range(&mut self, range: Range<usize>, replacement: impl IntoIterator<Item = T>) -> Vec<T> {
+        Vec::splice(self, range, replacement).collect()
+    }
+
+    /// Removes and returns the element at `index`, shifting all elements after it to the left.
+    ///
+    /// The returned element is no longer guarded. See [`Vec::remove`] for details.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        Vec::remove(self, index)
+    }
+
+    /// Inserts `element` at `index`, shifting all elements after it to the right.
+    ///
+    /// See [`Vec::insert`] for details.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is greater than the vector's length.
+    pub fn insert(&mut self, index: usize, element: T) {
+        Vec::insert(self, index, element);
+    }
+
+    /// Returns an iterator over the guarded vector's elements.
+    pub fn iter(&self) -> Iter<'_, T> {
+        <[T]>::iter(self)
+    }
+
+    /// Returns an iterator that allows modifying each of the guarded vector's elements.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        <[T]>::iter_mut(self)
+    }
+
+    /// Returns an iterator over the guarded vector's elements, paired with their index.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(vec!['a', 'b']);
+    /// assert_eq!(wrapper.iter_enumerate().collect::<Vec<_>>(), vec![(0, &'a'), (1, &'b')]);
+    /// wrapper.forget();
+    /// ```
+    pub fn iter_enumerate(&self) -> impl Iterator<Item = (usize, &T)> + '_ {
+        <[T]>::iter(self).enumerate()
+    }
+
+    /// Returns whether `pred` returns `true` for any of the guarded vector's elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(vec![1, 2, 3]);
+    /// assert!(wrapper.any(|&n| n > 2));
+    /// assert!(!wrapper.all(|&n| n > 2));
+    /// wrapper.forget();
+    /// ```
+    pub fn any(&self, pred: impl FnMut(&T) -> bool) -> bool {
+        <[T]>::iter(self).any(pred)
+    }
+
+    /// Returns whether `pred` returns `true` for all of the guarded vector's elements.
+    pub fn all(&self, pred: impl FnMut(&T) -> bool) -> bool {
+        <[T]>::iter(self).all(pred)
+    }
+
+    /// Returns how many of the guarded vector's elements `pred` returns `true` for.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(vec![1, 2, 3, 4]);
+    /// assert_eq!(wrapper.count_if(|&n| n % 2 == 0), 2);
+    /// wrapper.forget();
+    /// ```
+    pub fn count_if(&self, mut pred: impl FnMut(&T) -> bool) -> usize {
+        <[T]>::iter(self).filter(|element| pred(element)).count()
+    }
+
+    /// Returns the index of the first element `pred` returns `true` for, or [`None`] if there is
+    /// none.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(vec!['a', 'b', 'c']);
+    /// assert_eq!(wrapper.position(|&c| c == 'b'), Some(1));
+    /// wrapper.forget();
+    /// ```
+    pub fn position(&self, pred: impl FnMut(&T) -> bool) -> Option<usize> {
+        <[T]>::iter(self).position(pred)
+    }
+
+    /// Removes and returns the last element, or [`None`] if the vector is empty.
+    ///
+    /// The returned element is no longer guarded. See [`Vec::pop`] for details.
+    pub fn pop(&mut self) -> Option<T> {
+        Vec::pop(self)
+    }
+
+    /// Appends `value` to the back of the guarded vector.
+    ///
+    /// See [`Vec::push`] for details.
+    pub fn push(&mut self, value: T) {
+        Vec::push(self, value);
+    }
+
+    /// Removes all but the first of consecutive elements that resolve to the same key with `f`.
+    ///
+    /// See [`Vec::dedup_by_key`] for details.
+    pub fn dedup_by_key<K: PartialEq>(&mut self, f: impl FnMut(&mut T) -> K) {
+        Vec::dedup_by_key(self, f);
+    }
+
+    /// Reverses the order of the elements in the guarded vector, in place.
+    pub fn reverse(&mut self) {
+        <[T]>::reverse(self);
+    }
+
+    /// Consumes the guard, returning an owning iterator over the vector's elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(vec![1, 2, 3]);
+    /// assert_eq!(wrapper.into_iter_consuming().sum::<i32>(), 6);
+    /// ```
+    pub fn into_iter_consuming(self) -> IntoIter<T> {
+        self.consume().into_iter()
+    }
+
+    /// Consumes the guard, splitting the vector into `size` element chunks, each in its own newly
+    /// armed guard. The last chunk may be shorter.
+    ///
+    /// Every chunk must be consumed, including any left in the iterator, which [`panic!`] when the
+    /// iterator is dropped.
+    ///
+    /// # Panics
+    ///
+    /// If `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(vec![1, 2, 3, 4, 5]);
+    /// let chunks: Vec<_> = wrapper.into_chunks(2).map(NoDrop::consume).collect();
+    /// assert_eq!(chunks, vec![vec![1, 2], vec![3, 4], vec![5]]);
+    /// ```
+    #[track_caller]
+    pub fn into_chunks(self, size: usize) -> impl DoubleEndedIterator<Item = NoDropEmpty<Vec<T>>> {
+        let mut values = self.consume();
+        assert!(size != 0, "chunk size must be non-zero");
+
+        let mut chunks = Vec::with_capacity(values.len().div_ceil(size));
+        // Splitting from the back only moves each element once.
+        for start in (0..values.len()).step_by(size).rev() {
+            chunks.push(NoDropEmpty::wrap(values.split_off(start)));
+        }
+        chunks.into_iter().rev()
+    }
+
+    /// Consumes the guard, splitting off the first element, and returning it along with a newly
+    /// armed guard over the rest.
+    ///
+    /// # Errors
+    ///
+    /// If the vector is empty, returning the original guard.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(vec![1, 2, 3]);
+    /// let (first, rest) = wrapper.guarded_split_first().unwrap();
+    /// assert_eq!(first, 1);
+    /// assert_eq!(rest.consume(), vec![2, 3]);
+    /// ```
+    #[track_caller]
+    pub fn guarded_split_first(self) -> Result<(T, Self), Self> {
+        if self.is_empty() {
+            return Err(self);
+        }
+
+        let mut values = self.consume();
+        let first = values.remove(0);
+        Ok((first, NoDropEmpty::wrap(values)))
+    }
+
+    /// Consumes the guard, folding the vector's elements into an accumulator with `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(vec![1, 2, 3]);
+    /// assert_eq!(wrapper.fold(0, |sum, n| sum + n), 6);
+    /// ```
+    pub fn fold<B>(self, init: B, f: impl FnMut(B, T) -> B) -> B {
+        self.into_iter_consuming().fold(init, f)
+    }
+}
+
+impl<T: Ord> NoDropEmpty<Vec<T>> {
+    /// Sorts the guarded vector, then removes consecutive duplicates, leaving only unique elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let mut wrapper = NoDrop::wrap(vec![3, 1, 3, 2, 1]);
+    /// wrapper.sort_dedup();
+    /// assert_eq!(wrapper.consume(), vec![1, 2, 3]);
+    /// ```
+    pub fn sort_dedup(&mut self) {
+        <[T]>::sort(self);
+        Vec::dedup(self);
+    }
+
+    /// Returns the smallest element of the guarded vector, or [`None`] if it is empty.
+    ///
+    /// Named to avoid [`Ord::min`], which the guard itself implements, and which would otherwise
+    /// take precedence in method calls.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(vec![3, 1, 2]);
+    /// assert_eq!(wrapper.guarded_min(), Some(&1));
+    /// wrapper.forget();
+    /// ```
+    pub fn guarded_min(&self) -> Option<&T> {
+        <[T]>::iter(self).min()
+    }
+
+    /// Returns the largest element of the guarded vector, or [`None`] if it is empty.
+    ///
+    /// Named to avoid [`Ord::max`]; see [`guarded_min`](Self::guarded_min).
+    pub fn guarded_max(&self) -> Option<&T> {
+        <[T]>::iter(self).max()
+    }
+}
+
+impl<T> NoDropEmpty<Vec<Vec<T>>> {
+    /// Consumes the guard, flattening the nested vectors into a single newly guarded vector.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDrop;
+    ///
+    /// let wrapper = NoDrop::wrap(vec![vec![1, 2], vec![3]]);
+    /// assert_eq!(wrapper.guarded_concat().consume(), vec![1, 2, 3]);
+    /// ```
+    pub fn guarded_concat(self) -> NoDropEmpty<Vec<T>> {
+        self.map(|nested| nested.into_iter().flatten().collect())
+    }
+}
+
+impl<T: Clone> NoDropEmpty<Box<[T]>> {
+    /// Clones the guarded slice into a new, unguarded, [`Vec`].
+    ///
+    /// The guard remains armed over the original slice.
+    #[must_use]
+    pub fn to_vec(&self) -> Vec<T> {
+        <[T]>::to_vec(self)
+    }
+}
+
+/// Consumes each guard in `guards`, in order, passing its index and value to `f`.
+///
+/// # Examples
+///
+/// ```rust
+/// use no_drop::{consume_indexed, rls::NoDrop};
+///
+/// let guards = vec![NoDrop::wrap('a'), NoDrop::wrap('b')];
+/// let mut seen = Vec::new();
+/// consume_indexed(guards, |index, value| seen.push((index, value)));
+/// assert_eq!(seen, vec![(0, 'a'), (1, 'b')]);
+/// ```
+pub fn consume_indexed<T>(guards: Vec<NoDropEmpty<T>>, mut f: impl FnMut(usize, T)) {
+    guards.into_iter().map(NoDropEmpty::consume).enumerate().for_each(|(index, value)| f(index, value));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks_mut_mutates_chunks() {
+        let mut wrapper = NoDropEmpty::wrap(vec![1, 2, 3, 4, 5]);
+        wrapper.chunks_mut(2).flatten().for_each(|value| *value *= 2);
+        assert_eq!(wrapper.consume(), vec![2, 4, 6, 8, 10]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn chunks_mut_stays_armed() {
+        let mut wrapper = NoDropEmpty::wrap(vec![1, 2, 3]);
+        wrapper.chunks_mut(2).for_each(|chunk| chunk[0] = 0);
+        drop(wrapper);
+    }
+
+    #[test]
+    fn swap_remove_returns_element() {
+        let mut wrapper = NoDropEmpty::wrap(vec![1, 2, 3, 4]);
+        assert_eq!(wrapper.swap_remove(1), 2);
+        assert_eq!(wrapper.consume(), vec![1, 4, 3]);
+    }
+
+    #[test]
+    fn guarded_get_checks_bounds() {
+        let mut wrapper = NoDropEmpty::wrap(vec![1, 2, 3]);
+        assert_eq!(wrapper.guarded_get(1), Some(&2));
+        assert_eq!(wrapper.guarded_get(3), None);
+        assert_eq!(wrapper.get(0..2), Some(&[1, 2][..]));
+
+        *wrapper.guarded_get_mut(0).unwrap() = 10;
+        assert_eq!(wrapper.guarded_get_mut(3), None);
+        assert_eq!(wrapper.consume(), vec![10, 2, 3]);
+    }
+
+    #[test]
+    fn as_chunks_splits_remainder() {
+        let wrapper = NoDropEmpty::wrap(vec![1, 2, 3, 4, 5]);
+        let (chunks, remainder) = wrapper.as_chunks::<2>();
+        assert_eq!(chunks, &[[1, 2], [3, 4]]);
+        assert_eq!(remainder, &[5]);
+        assert_eq!(wrapper.consume(), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn splice_range_replaces_elements() {
+        let mut wrapper = NoDropEmpty::wrap(vec![1, 2, 3, 4, 5]);
+        assert_eq!(wrapper.splice_range(1..3, [20, 30, 40]), vec![2, 3]);
+        assert_eq!(wrapper.consume(), vec![1, 20, 30, 40, 4, 5]);
+    }
+
+    #[test]
+    fn remove_shifts_elements() {
+        let mut wrapper = NoDropEmpty::wrap(vec![1, 2, 3, 4]);
+        assert_eq!(wrapper.remove(1), 2);
+        assert_eq!(wrapper.consume(), vec![1, 3, 4]);
+    }
+
+    #[test]
+    fn insert_front_middle_end() {
+        let mut wrapper = NoDropEmpty::wrap(vec![2, 4]);
+        wrapper.insert(0, 1);
+        wrapper.insert(2, 3);
+        wrapper.insert(4, 5);
+        assert_eq!(wrapper.consume(), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn insert_stays_armed() {
+        let mut wrapper = NoDropEmpty::wrap(vec![1]);
+        wrapper.insert(0, 0);
+        drop(wrapper);
+    }
+
+    #[test]
+    fn iter_and_iter_mut() {
+        let mut wrapper = NoDropEmpty::wrap(vec![1, 2, 3]);
+        assert_eq!(wrapper.iter().sum::<i32>(), 6);
+
+        wrapper.iter_mut().for_each(|value| *value += 1);
+        assert_eq!(wrapper.consume(), vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn pop_until_empty() {
+        let mut wrapper = NoDropEmpty::wrap(vec![1, 2]);
+        assert_eq!(wrapper.pop(), Some(2));
+        assert_eq!(wrapper.pop(), Some(1));
+        assert_eq!(wrapper.pop(), None);
+        assert_eq!(wrapper.consume(), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn push_accumulates() {
+        let mut wrapper = NoDropEmpty::wrap(Vec::new());
+        wrapper.push(1);
+        wrapper.push(2);
+        wrapper.push(3);
+        assert_eq!(wrapper.consume(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn push_stays_armed() {
+        let mut wrapper = NoDropEmpty::wrap(Vec::new());
+        wrapper.push(1);
+        drop(wrapper);
+    }
+
+    #[test]
+    fn reverse_vec() {
+        let mut wrapper = NoDropEmpty::wrap(vec![1, 2, 3]);
+        wrapper.reverse();
+        assert_eq!(wrapper.consume(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn reverse_vec_stays_armed() {
+        let mut wrapper = NoDropEmpty::wrap(vec![1, 2, 3]);
+        wrapper.reverse();
+        drop(wrapper);
+    }
+
+    #[test]
+    fn iter_enumerate_pairs() {
+        let wrapper = NoDropEmpty::wrap(vec![10, 20, 30]);
+        assert_eq!(wrapper.iter_enumerate().collect::<Vec<_>>(), vec![(0, &10), (1, &20), (2, &30)]);
+        assert_eq!(wrapper.consume(), vec![10, 20, 30]);
+    }
+
+    #[test]
+    fn sort_dedup_vec() {
+        let mut wrapper = NoDropEmpty::wrap(vec![3, 1, 2, 3, 1]);
+        wrapper.sort_dedup();
+        assert_eq!(wrapper.consume(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn sort_dedup_stays_armed() {
+        let mut wrapper = NoDropEmpty::wrap(vec![3, 1, 3]);
+        wrapper.sort_dedup();
+        drop(wrapper);
+    }
+
+    #[test]
+    fn min_max_populated() {
+        let wrapper = NoDropEmpty::wrap(vec![3, 1, 2]);
+        assert_eq!(wrapper.guarded_min(), Some(&1));
+        assert_eq!(wrapper.guarded_max(), Some(&3));
+        assert_eq!(wrapper.consume(), vec![3, 1, 2]);
+    }
+
+    #[test]
+    fn min_max_empty() {
+        let wrapper = NoDropEmpty::wrap(Vec::<i32>::new());
+        assert_eq!(wrapper.guarded_min(), None);
+        assert_eq!(wrapper.guarded_max(), None);
+        assert!(wrapper.consume().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn min_max_stay_armed() {
+        let wrapper = NoDropEmpty::wrap(vec![3, 1, 2]);
+        let _extremes = (wrapper.guarded_min(), wrapper.guarded_max());
+        drop(wrapper);
+    }
+
+    #[test]
+    fn into_iter_consuming_collects() {
+        let wrapper = NoDropEmpty::wrap(vec![1, 2, 3]);
+        assert_eq!(wrapper.into_iter_consuming().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn guarded_concat_flattens() {
+        let wrapper = NoDropEmpty::wrap(vec![vec![1, 2], vec![], vec![3]]);
+        assert_eq!(wrapper.concat(), vec![1, 2, 3]);
+        assert_eq!(wrapper.guarded_concat().consume(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn guarded_concat_stays_armed() {
+        let wrapper = NoDropEmpty::wrap(vec![vec![1, 2], vec![3]]);
+        drop(wrapper.guarded_concat());
+    }
+
+    #[test]
+    fn boxed_slice_to_vec() {
+        let wrapper = NoDropEmpty::wrap(Box::<[i32]>::from([1, 2, 3]));
+        assert_eq!(wrapper.to_vec(), vec![1, 2, 3]);
+        assert_eq!(&*wrapper.consume(), &[1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn boxed_slice_to_vec_stays_armed() {
+        let wrapper = NoDropEmpty::wrap(Box::<[i32]>::from([1, 2, 3]));
+        let _snapshot = wrapper.to_vec();
+        drop(wrapper);
+    }
+
+    #[test]
+    fn consume_indexed_passes_indices_in_order() {
+        let guards = vec![NoDropEmpty::wrap(10), NoDropEmpty::wrap(20), NoDropEmpty::wrap(30)];
+        let mut seen = Vec::new();
+        consume_indexed(guards, |index, value| seen.push((index, value)));
+        assert_eq!(seen, vec![(0, 10), (1, 20), (2, 30)]);
+    }
+
+    #[test]
+    fn consume_indexed_empty() {
+        consume_indexed(Vec::<NoDropEmpty<i32>>::new(), |_, _| unreachable!());
+    }
+
+    #[test]
+    fn fold_sums() {
+        let wrapper = NoDropEmpty::wrap(vec![1, 2, 3, 4]);
+        assert_eq!(wrapper.fold(0, |sum, n| sum + n), 10);
+    }
+
+    #[test]
+    fn fold_concatenates() {
+        let wrapper = NoDropEmpty::wrap(vec!["a", "b", "c"]);
+        assert_eq!(wrapper.fold(String::new(), |acc, s| acc + s), "abc");
+    }
+
+    #[test]
+    fn any_all_satisfied() {
+        let wrapper = NoDropEmpty::wrap(vec![2, 4, 6]);
+        assert!(wrapper.any(|&n| n == 4));
+        assert!(wrapper.all(|&n| n % 2 == 0));
+        assert_eq!(wrapper.consume(), vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn any_all_unsatisfied() {
+        let wrapper = NoDropEmpty::wrap(vec![2, 4, 6]);
+        assert!(!wrapper.any(|&n| n == 5));
+        assert!(!wrapper.all(|&n| n > 2));
+        assert_eq!(wrapper.consume(), vec![2, 4, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn any_all_stay_armed() {
+        let wrapper = NoDropEmpty::wrap(vec![2, 4, 6]);
+        let _checks = (wrapper.any(|&n| n == 4), wrapper.all(|&n| n == 4));
+        drop(wrapper);
+    }
+
+    #[test]
+    fn count_if_counts_matches() {
+        let wrapper = NoDropEmpty::wrap(vec![1, 2, 3, 4, 6]);
+        assert_eq!(wrapper.count_if(|&n| n % 2 == 0), 3);
+        assert_eq!(wrapper.count_if(|&n| n > 10), 0);
+        assert_eq!(wrapper.consume(), vec![1, 2, 3, 4, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn count_if_stays_armed() {
+        let wrapper = NoDropEmpty::wrap(vec![1, 2, 3]);
+        let _count = wrapper.count_if(|&n| n % 2 == 0);
+        drop(wrapper);
+    }
+
+    #[test]
+    fn position_found() {
+        let wrapper = NoDropEmpty::wrap(vec![10, 20, 30]);
+        assert_eq!(wrapper.position(|&n| n == 20), Some(1));
+        assert_eq!(wrapper.consume(), vec![10, 20, 30]);
+    }
+
+    #[test]
+    fn position_not_found() {
+        let wrapper = NoDropEmpty::wrap(vec![10, 20, 30]);
+        assert_eq!(wrapper.position(|&n| n == 25), None);
+        assert_eq!(wrapper.consume(), vec![10, 20, 30]);
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Entry {
+        group: u8,
+        name: &'static str,
+    }
+
+    #[test]
+    fn dedup_by_key_field() {
+        let mut wrapper = NoDropEmpty::wrap(vec![
+            Entry { group: 1, name: "a" },
+            Entry { group: 1, name: "b" },
+            Entry { group: 2, name: "c" },
+            Entry { group: 1, name: "d" },
+        ]);
+        wrapper.dedup_by_key(|entry| entry.group);
+        assert_eq!(
+            wrapper.consume(),
+            vec![Entry { group: 1, name: "a" }, Entry { group: 2, name: "c" }, Entry { group: 1, name: "d" }]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn dedup_by_key_stays_armed() {
+        let mut wrapper = NoDropEmpty::wrap(vec![Entry { group: 1, name: "a" }, Entry { group: 1, name: "b" }]);
+        wrapper.dedup_by_key(|entry| entry.group);
+        drop(wrapper);
+    }
+
+    #[test]
+    fn into_chunks_yields_guarded_chunks() {
+        let wrapper = NoDropEmpty::wrap(vec![1, 2, 3, 4, 5]);
+        let chunks: Vec<_> = wrapper.into_chunks(2).collect();
+        assert_eq!(chunks.len(), 3);
+        let chunks: Vec<_> = chunks.into_iter().map(NoDropEmpty::consume).collect();
+        assert_eq!(chunks, vec![vec![1, 2], vec![3, 4], vec![5]]);
+    }
+
+    #[test]
+    fn into_chunks_empty() {
+        let wrapper = NoDropEmpty::wrap(Vec::<i32>::new());
+        assert_eq!(wrapper.into_chunks(2).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn into_chunks_chunk_is_armed() {
+        let wrapper = NoDropEmpty::wrap(vec![1, 2, 3, 4, 5]);
+        let mut chunks = wrapper.into_chunks(2);
+        chunks.next().unwrap().forget();
+        chunks.next().unwrap().forget();
+        drop(chunks.next());
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn into_chunks_zero_size() {
+        let _chunks = NoDropEmpty::wrap(vec![1, 2, 3]).into_chunks(0);
+    }
+
+    #[test]
+    fn guarded_split_first_non_empty() {
+        let wrapper = NoDropEmpty::wrap(vec![1, 2, 3]);
+        assert_eq!(wrapper.split_first(), Some((&1, &[2, 3][..])));
+        let (first, rest) = wrapper.guarded_split_first().unwrap();
+        assert_eq!(first, 1);
+        assert_eq!(rest.consume(), vec![2, 3]);
+    }
+
+    #[test]
+    fn guarded_split_first_empty() {
+        let wrapper = NoDropEmpty::wrap(Vec::<i32>::new());
+        let wrapper = wrapper.guarded_split_first().unwrap_err();
+        assert!(wrapper.consume().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn guarded_split_first_rest_is_armed() {
+        let wrapper = NoDropEmpty::wrap(vec![1, 2, 3]);
+        let (_first, rest) = wrapper.guarded_split_first().unwrap();
+        drop(rest);
+    }
+}
@@ -0,0 +1,121 @@
+use core::mem::ManuallyDrop;
+
+/// A wrapper around a `T` `value` that calls a handler `F` with the value if dropped without
+/// being [`Self::consume`]d or [`Self::forget`]ten.
+///
+/// Unlike [`NoDropEmpty`](super::NoDropEmpty) and [`NoDropMsg`](super::NoDropMsg) this type does
+/// not [`panic!`] on its own, allowing a forgotten value to be logged, counted, or reported
+/// instead. The handler is called at most once, and never if the value is consumed.
+#[derive(derive_more::Deref, derive_more::DerefMut, derive_more::AsMut, derive_more::AsRef)]
+#[must_use = "the value must be consumed, or the callback runs when it is dropped"]
+pub struct NoDropWith<T, F: FnOnce(&T)> {
+    #[deref]
+    #[deref_mut]
+    #[as_mut]
+    #[as_ref]
+    value: T,
+    on_drop: ManuallyDrop<F>,
+}
+
+impl<T, F: FnOnce(&T)> NoDropWith<T, F> {
+    /// Creates a new wrapper around `value`, that calls `on_drop` if dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use no_drop::rls::NoDropWith;
+    ///
+    /// let wrapper = NoDropWith::wrap(42, |value| eprintln!("{value} was dropped"));
+    /// assert_eq!(wrapper.consume(), 42);
+    /// ```
+    #[inline]
+    pub fn wrap(value: T, on_drop: F) -> Self {
+        Self { value, on_drop: ManuallyDrop::new(on_drop) }
+    }
+
+    /// Consumes the wrapper and returns the inner `T`, without calling the handler.
+    #[inline]
+    #[must_use]
+    pub fn consume(self) -> T {
+        let mut this = ManuallyDrop::new(self);
+        // SAFETY: `on_drop` is dropped exactly once and `T` is moved out of the wrapper exactly
+        // once, then this is dropped. No uninitialized access can occur.
+        unsafe {
+            ManuallyDrop::drop(&mut this.on_drop);
+            core::ptr::read(&raw const this.value)
+        }
+    }
+
+    /// Consumes the wrapper and returns the inner `T`.
+    #[deprecated(since = "0.3.0", note = "renamed to `consume`")]
+    #[inline]
+    #[must_use]
+    pub fn unwrap(self) -> T {
+        self.consume()
+    }
+
+    /// Forgets this guard, safely dropping it without calling the handler.
+    #[inline]
+    pub fn forget(self) {
+        drop(self.consume());
+    }
+}
+
+impl<T: core::fmt::Debug, F: FnOnce(&T)> core::fmt::Debug for NoDropWith<T, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("NoDropWith").field("value", &self.value).finish_non_exhaustive()
+    }
+}
+
+impl<T, F: FnOnce(&T)> Drop for NoDropWith<T, F> {
+    /// Calls the handler with the value.
+    fn drop(&mut self) {
+        // SAFETY: `on_drop` is taken exactly once, here, and never accessed again.
+        let on_drop = unsafe { ManuallyDrop::take(&mut self.on_drop) };
+        on_drop(&self.value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn handler_runs_once_on_drop() {
+        let calls = Cell::new(0);
+        let wrapper = NoDropWith::wrap(42, |value: &i32| {
+            assert_eq!(*value, 42);
+            calls.set(calls.get() + 1);
+        });
+        drop(wrapper);
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn handler_not_run_on_unwrap() {
+        let calls = Cell::new(0);
+        let wrapper = NoDropWith::wrap(42, |_: &i32| calls.set(calls.get() + 1));
+        assert_eq!(wrapper.consume(), 42);
+        assert_eq!(calls.get(), 0);
+    }
+
+    #[test]
+    fn handler_not_run_on_forget() {
+        let calls = Cell::new(0);
+        let wrapper = NoDropWith::wrap(42, |_: &i32| calls.set(calls.get() + 1));
+        wrapper.forget();
+        assert_eq!(calls.get(), 0);
+    }
+
+    #[test]
+    fn unwrap_drops_handler() {
+        let captured = std::rc::Rc::new(());
+        let handle = std::rc::Rc::clone(&captured);
+        let wrapper = NoDropWith::wrap(42, move |_: &i32| drop(handle));
+        assert_eq!(std::rc::Rc::strong_count(&captured), 2);
+        assert_eq!(wrapper.consume(), 42);
+        assert_eq!(std::rc::Rc::strong_count(&captured), 1);
+    }
+}
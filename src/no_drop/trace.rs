@@ -0,0 +1,36 @@
+#[cfg(feature = "backtrace")]
+use alloc::sync::Arc;
+use core::fmt;
+#[cfg(feature = "backtrace")]
+use std::backtrace::Backtrace;
+
+/// A backtrace of where a guard was created, reported if it is dropped while armed.
+///
+/// This is only captured with the `backtrace` feature. Without it, this is zero-sized, and is
+/// formatted as nothing.
+///
+/// Only [`NoDropMsg`](super::NoDropMsg), and so `DropGuard`, hold one. [`NoDropEmpty`](super::NoDropEmpty)
+/// does not, even with the feature enabled, since it must stay constructible in `const` contexts, where
+/// no backtrace can be captured.
+#[derive(Debug, Clone)]
+pub(crate) struct Trace(#[cfg(feature = "backtrace")] Arc<Backtrace>);
+
+impl Trace {
+    /// Captures a backtrace of the caller, regardless of the `RUST_BACKTRACE` setting.
+    #[inline]
+    pub(crate) fn capture() -> Self {
+        #[cfg(feature = "backtrace")]
+        return Self(Arc::new(Backtrace::force_capture()));
+        #[cfg(not(feature = "backtrace"))]
+        Self()
+    }
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "backtrace")]
+        return write!(f, "\n\ncreation backtrace:\n{}", self.0);
+        #[cfg(not(feature = "backtrace"))]
+        f.write_str("")
+    }
+}
@@ -0,0 +1,130 @@
+//! Test-only switches and assertions for the [`panic!`]ing guards.
+//!
+//! Tests that deliberately leave guards armed can otherwise fail, or abort the test binary if the
+//! guard is dropped during an unwind. While a [`DisableGuard`] is alive on a thread, guards dropped
+//! on that thread while armed do not [`panic!`]. They are also not counted by the `metrics` feature,
+//! logged by the `tracing` feature, or passed to the `hooks` hook.
+//!
+//! [`expect_consumed`], and the [`assert_consumed!`](crate::assert_consumed) macro, check the
+//! opposite, that a code path consumes every guard it creates.
+//!
+//! This is behind the `testing` feature, which should only be enabled as a dev-dependency, so it
+//! cannot defeat the guards in production.
+
+use core::cell::Cell;
+use core::marker::PhantomData;
+
+use crate::guards::catch_unconsumed;
+use crate::no_drop::NoDrop;
+
+std::thread_local! {
+    static INERT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Makes the [`panic!`]ing guards inert on the current thread, until dropped.
+///
+/// Scopes may be nested. Dropping one restores the state from before it was created.
+///
+/// This is `!Send`, since it only affects the thread it was created on.
+///
+/// # Examples
+///
+/// ```rust
+/// use no_drop::rls::NoDrop;
+/// use no_drop::testing::DisableGuard;
+///
+/// {
+///     let _inert = DisableGuard::new();
+///     drop(NoDrop::wrap(42)); // does not panic
+/// }
+///
+/// let result = std::panic::catch_unwind(|| drop(NoDrop::wrap(42)));
+/// assert!(result.is_err());
+/// ```
+#[derive(Debug)]
+#[must_use = "guards are only inert while the `DisableGuard` is alive"]
+pub struct DisableGuard {
+    previous: bool,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl DisableGuard {
+    /// Makes the guards inert on the current thread, until the returned scope is dropped.
+    pub fn new() -> Self {
+        Self { previous: INERT.replace(true), _not_send: PhantomData }
+    }
+}
+
+impl Default for DisableGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for DisableGuard {
+    fn drop(&mut self) {
+        INERT.set(self.previous);
+    }
+}
+
+/// Returns whether the guards are inert on the current thread.
+#[must_use]
+pub fn guards_inert() -> bool {
+    INERT.get()
+}
+
+/// Runs `body`, and consumes the guard it returns, returning its value.
+///
+/// This turns the only observable failure of a guard, a [`panic!`] when it is dropped armed, into a
+/// test assertion. A test can check a code path consumes every guard it creates, by returning the
+/// one it hands back from `body`.
+///
+/// # Panics
+///
+/// Panics, reporting the caller's location, if a guard is dropped while armed inside `body`. Other
+/// [`panic!`]s from `body` are propagated unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// use no_drop::rls::NoDrop;
+/// use no_drop::testing::expect_consumed;
+///
+/// let value = expect_consumed(|| {
+///     let staged = NoDrop::wrap(21);
+///     NoDrop::wrap(staged.consume() * 2)
+/// });
+/// assert_eq!(value, 42);
+/// ```
+#[track_caller]
+pub fn expect_consumed<T>(body: impl FnOnce() -> NoDrop<T>) -> T {
+    match catch_unconsumed(body) {
+        Ok(guard) => guard.consume(),
+        Err(leaked) => panic!("expected every guard to be consumed, but one leaked: {}", leaked.message()),
+    }
+}
+
+/// Asserts that evaluating an expression, which produces a [`NoDrop`](crate::rls::NoDrop), does
+/// not drop any other guard while armed, and evaluates to the guarded value.
+///
+/// `assert_consumed!(expr)` is shorthand for [`expect_consumed`]`(|| expr)`, and a failure is
+/// reported at the macro's location.
+///
+/// # Examples
+///
+/// ```rust
+/// use no_drop::assert_consumed;
+/// use no_drop::rls::NoDrop;
+///
+/// fn stage(value: NoDrop<i32>) -> NoDrop<i32> {
+///     value.map(|n| n + 1)
+/// }
+///
+/// assert_eq!(assert_consumed!(stage(NoDrop::wrap(41))), 42);
+/// ```
+#[macro_export]
+macro_rules! assert_consumed {
+    ($body:expr $(,)?) => {
+        $crate::testing::expect_consumed(|| $body)
+    };
+}
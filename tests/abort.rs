@@ -0,0 +1,84 @@
+//! Tests for the `abort` module.
+//!
+//! Aborting cannot be observed in-process, so each test re-runs itself as a child process with
+//! `CHILD_ENV` set, and asserts the child was aborted.
+#![cfg(all(unix, feature = "std"))]
+
+use std::os::unix::process::ExitStatusExt;
+use std::process::{Command, Output};
+
+use no_drop::abort::*;
+
+const CHILD_ENV: &str = "NO_DROP_ABORT_CHILD";
+const SIGABRT: i32 = 6;
+
+fn is_child() -> bool {
+    std::env::var_os(CHILD_ENV).is_some()
+}
+
+fn run_child(test_name: &str) -> Output {
+    Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", test_name, "--nocapture", "--test-threads=1"])
+        .env(CHILD_ENV, "1")
+        .output()
+        .unwrap()
+}
+
+fn assert_aborted(output: &Output, msg: &str) {
+    assert_eq!(output.status.signal(), Some(SIGABRT));
+    assert!(String::from_utf8_lossy(&output.stderr).contains(msg));
+}
+
+#[test]
+fn no_drop_aborts() {
+    if is_child() {
+        let _value = NoDrop::wrap(42);
+        return;
+    }
+    assert_aborted(&run_child("no_drop_aborts"), DEFAULT_DROP_PANIC_MSG);
+}
+
+#[test]
+fn no_drop_msg_aborts() {
+    if is_child() {
+        let _value = NoDropMsg::wrap(42, "custom abort message");
+        return;
+    }
+    assert_aborted(&run_child("no_drop_msg_aborts"), "custom abort message");
+}
+
+#[test]
+fn drop_guard_aborts() {
+    if is_child() {
+        let _guard = DropGuard::new_armed("armed guard dropped");
+        return;
+    }
+    assert_aborted(&run_child("drop_guard_aborts"), "armed guard dropped");
+}
+
+#[test]
+fn abort_reports_origin() {
+    if is_child() {
+        let _value = NoDrop::wrap(42);
+        return;
+    }
+    assert_aborted(&run_child("abort_reports_origin"), &format!("(created at {}:", file!()));
+}
+
+#[test]
+fn abort_cannot_be_caught() {
+    if is_child() {
+        let _ = std::panic::catch_unwind(|| drop(DropGuardEmpty::new_armed()));
+        return;
+    }
+    assert_aborted(&run_child("abort_cannot_be_caught"), DEFAULT_DROP_PANIC_MSG);
+}
+
+#[test]
+fn consumed_does_not_abort() {
+    let value = NoDrop::wrap(42);
+    assert_eq!(value.consume(), 42);
+
+    let mut guard = DropGuard::new_armed("message");
+    guard.disarm();
+}
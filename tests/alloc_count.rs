@@ -0,0 +1,61 @@
+//! Tests that messages are not reallocated, using a counting global allocator.
+//!
+//! Capturing a creation backtrace allocates, so these only run without the `backtrace` feature.
+#![cfg(all(feature = "std", not(feature = "backtrace")))]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use no_drop::rls::{DropGuard, NoDropMsg};
+
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Returns the number of allocations made on this thread while running `f`.
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn static_msg_does_not_allocate() {
+    let count = allocations(|| {
+        let mut guard = DropGuard::new_armed("static message");
+        assert!(guard.disarm());
+        assert!(guard.arm());
+        assert!(guard.disarm());
+
+        assert_eq!(NoDropMsg::wrap(42, "static message").consume(), 42);
+    });
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn owned_msg_is_not_reallocated() {
+    let mut guard = DropGuard::new_disarmed(String::from("owned message"));
+    let count = allocations(|| {
+        assert!(guard.arm());
+        assert!(guard.disarm());
+        assert!(guard.arm());
+        assert!(guard.disarm());
+    });
+    assert_eq!(count, 0);
+}
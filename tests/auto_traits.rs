@@ -0,0 +1,89 @@
+//! Compile-time checks that the auto traits and variance of each type follow its contents.
+//!
+//! Each check only needs to type check, so the tests themselves do nothing at runtime.
+use std::panic::{RefUnwindSafe, UnwindSafe};
+
+fn assert_auto_traits<T: Send + Sync + Unpin>() {}
+
+fn assert_unwind_safe<T: UnwindSafe + RefUnwindSafe>() {}
+
+macro_rules! assert_module_auto_traits {
+    ($module:ident) => {
+        mod $module {
+            use super::{assert_auto_traits, assert_unwind_safe};
+            use no_drop::$module::*;
+
+            #[test]
+            fn no_drop() {
+                assert_auto_traits::<NoDrop>();
+                assert_auto_traits::<NoDrop<i32>>();
+                assert_unwind_safe::<NoDrop<i32>>();
+            }
+
+            #[test]
+            fn drop_guard_empty() {
+                assert_auto_traits::<DropGuardEmpty>();
+                assert_unwind_safe::<DropGuardEmpty<i32>>();
+            }
+
+            #[cfg(feature = "alloc")]
+            #[test]
+            fn no_drop_msg() {
+                assert_auto_traits::<NoDropMsg<'static, i32>>();
+                assert_unwind_safe::<NoDropMsg<'static, i32>>();
+            }
+
+            #[cfg(feature = "alloc")]
+            #[test]
+            fn drop_guard() {
+                assert_auto_traits::<DropGuard<'static>>();
+                assert_unwind_safe::<DropGuard<'static>>();
+            }
+
+            #[cfg(feature = "alloc")]
+            #[allow(dead_code)]
+            fn no_drop_msg_is_covariant<'a>(value: NoDropMsg<'static, &'static str>) -> NoDropMsg<'a, &'a str> {
+                value
+            }
+
+            #[cfg(feature = "alloc")]
+            #[allow(dead_code)]
+            fn drop_guard_is_covariant<'a>(guard: DropGuard<'static>) -> DropGuard<'a> {
+                guard
+            }
+        }
+    };
+}
+
+assert_module_auto_traits!(dbg);
+assert_module_auto_traits!(rls);
+
+#[allow(dead_code)]
+fn no_drop_is_covariant<'a>(value: no_drop::rls::NoDrop<&'static str>) -> no_drop::rls::NoDrop<&'a str> {
+    value
+}
+
+#[test]
+fn auto_traits_follow_value() {
+    use no_drop::rls::{DropGuardEmpty, NoDrop};
+    use std::cell::Cell;
+
+    // `Cell` is `Send` but not `Sync`, so a guard holding one is only `Send`.
+    fn assert_send<T: Send>() {}
+    assert_send::<NoDrop<Cell<i32>>>();
+    assert_send::<DropGuardEmpty<Cell<i32>>>();
+}
+
+#[cfg(feature = "std")]
+mod abort {
+    use super::assert_auto_traits;
+    use no_drop::abort::*;
+
+    #[test]
+    fn abort_types() {
+        assert_auto_traits::<NoDrop<i32>>();
+        assert_auto_traits::<NoDropMsg<'static, i32>>();
+        assert_auto_traits::<DropGuardEmpty>();
+        assert_auto_traits::<DropGuard<'static>>();
+    }
+}
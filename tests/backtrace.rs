@@ -0,0 +1,31 @@
+//! Tests for the `backtrace` feature.
+#![cfg(feature = "backtrace")]
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use no_drop::rls::{DropGuard, NoDropMsg};
+
+fn drop_panic_message(value: impl Sized) -> String {
+    let payload = catch_unwind(AssertUnwindSafe(|| drop(value))).unwrap_err();
+    payload.downcast_ref::<String>().unwrap().clone()
+}
+
+#[test]
+fn drop_panic_includes_backtrace() {
+    let msg = drop_panic_message(NoDropMsg::wrap(42, "custom message"));
+    assert!(msg.starts_with("custom message (created at "), "{msg}");
+    assert!(msg.contains("creation backtrace:\n"), "{msg}");
+    assert!(msg.contains("0: "), "missing frames: {msg}");
+}
+
+#[test]
+fn armed_guard_panic_includes_backtrace() {
+    let msg = drop_panic_message(DropGuard::new_armed("guard message"));
+    assert!(msg.starts_with("guard message (created at "), "{msg}");
+    assert!(msg.contains("creation backtrace:\n"), "{msg}");
+}
+
+#[test]
+fn consumed_value_does_not_panic() {
+    assert_eq!(NoDropMsg::wrap(42, "custom message").consume(), 42);
+}
@@ -0,0 +1,7 @@
+//! Compile-fail tests for the derive macros, and the lints and visibility the public API relies on.
+#![cfg(all(feature = "alloc", feature = "derive"))]
+
+#[test]
+fn compile_fail() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,50 @@
+//! Tests for the notice printed by `debug_forget` in debug builds.
+//!
+//! The notice is only printed to stderr, so each test re-runs itself as a child process with
+//! `CHILD_ENV` set, and checks the child's stderr.
+#![cfg(all(feature = "std", debug_assertions))]
+
+use std::process::Command;
+
+use no_drop::rls::{NoDrop, DEFAULT_DROP_PANIC_MSG};
+
+const CHILD_ENV: &str = "NO_DROP_DEBUG_FORGET_CHILD";
+
+fn is_child() -> bool {
+    std::env::var_os(CHILD_ENV).is_some()
+}
+
+fn child_stderr(test_name: &str) -> String {
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", test_name, "--nocapture", "--test-threads=1"])
+        .env(CHILD_ENV, "1")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn debug_forget_notices() {
+    let line = line!() + 2;
+    if is_child() {
+        NoDrop::wrap(42).debug_forget();
+        return;
+    }
+    let stderr = child_stderr("debug_forget_notices");
+    let location = format!("{}:{line}:", file!());
+    assert!(stderr.contains(&format!("armed value forgotten (created at {location}")), "{stderr}");
+    assert!(stderr.contains(DEFAULT_DROP_PANIC_MSG), "{stderr}");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn debug_forget_notices_message() {
+    if is_child() {
+        no_drop::rls::NoDropMsg::wrap(42, "upload not committed").debug_forget();
+        return;
+    }
+    let stderr = child_stderr("debug_forget_notices_message");
+    assert!(stderr.contains("armed value forgotten (created at "), "{stderr}");
+    assert!(stderr.contains(": upload not committed"), "{stderr}");
+}
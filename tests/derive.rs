@@ -0,0 +1,87 @@
+//! Tests for the `NoDropConsume` derive macro.
+#![cfg(feature = "derive")]
+
+use no_drop::NoDropConsume;
+
+#[derive(Debug, NoDropConsume)]
+struct Pair {
+    name: String,
+    values: Vec<u8>,
+}
+
+#[derive(Debug, NoDropConsume)]
+struct Wrapper<T>(T, u32);
+
+#[test]
+fn consume_returns_fields() {
+    let pair = Pair { name: String::from("pair"), values: vec![1, 2, 3] };
+    let (name, values) = pair.consume();
+    assert_eq!(name, "pair");
+    assert_eq!(values, [1, 2, 3]);
+}
+
+#[test]
+fn consume_tuple_struct() {
+    let wrapper = Wrapper(String::from("value"), 7);
+    assert_eq!(wrapper.consume(), (String::from("value"), 7));
+}
+
+#[test]
+#[should_panic(expected = "Pair was dropped without being consumed")]
+fn panics_on_drop() {
+    let _pair = Pair { name: String::from("pair"), values: vec![1, 2, 3] };
+}
+
+#[test]
+#[should_panic(expected = "Wrapper was dropped without being consumed")]
+fn panics_on_drop_generic() {
+    let _wrapper = Wrapper(42, 7);
+}
+
+mod must_consume {
+    use no_drop::must_consume;
+
+    #[derive(Debug, PartialEq)]
+    struct Commit(u32);
+
+    #[derive(Debug, PartialEq)]
+    struct Log(&'static str);
+
+    #[must_consume]
+    struct Transaction {
+        #[must_consume]
+        commit: Commit,
+        #[must_consume]
+        log: Log,
+        retries: u32,
+    }
+
+    #[must_consume]
+    struct Pair<T>(#[must_consume] T, u8);
+
+    #[test]
+    fn consume_returns_fields() {
+        let transaction = Transaction::new(Commit(1), Log("log"), 3);
+        assert_eq!(transaction.commit.0, 1);
+        assert_eq!(transaction.retries, 3);
+        assert_eq!(transaction.consume(), (Commit(1), Log("log"), 3));
+    }
+
+    #[test]
+    fn consume_tuple_struct() {
+        let pair = Pair::new(String::from("value"), 7);
+        assert_eq!(pair.consume(), (String::from("value"), 7));
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn panics_on_drop() {
+        let _transaction = Transaction::new(Commit(1), Log("log"), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value was dropped without being consumed")]
+    fn panics_on_drop_tuple_struct() {
+        let _pair = Pair::new(42, 7);
+    }
+}
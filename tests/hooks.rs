@@ -0,0 +1,60 @@
+//! Tests for the `hooks` feature.
+//!
+//! The hook and policy are global, so this file holds a single test, to avoid racing with other
+//! tests.
+#![cfg(feature = "hooks")]
+
+use std::panic::catch_unwind;
+use std::sync::{Arc, Mutex};
+
+use no_drop::hooks::{clear_on_leak, leak_policy, set_leak_policy, set_on_leak, LeakPolicy};
+use no_drop::rls::{DropGuardEmpty, NoDrop, DEFAULT_DROP_PANIC_MSG};
+
+#[test]
+fn hook_and_policy() {
+    assert_eq!(leak_policy(), LeakPolicy::Panic);
+
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&reports);
+    set_on_leak(move |report| recorded.lock().unwrap().push(report.to_string()));
+
+    // Log only: the hook sees every leak, and nothing panics.
+    set_leak_policy(LeakPolicy::LogOnly);
+    drop(NoDrop::wrap(42));
+    drop(DropGuardEmpty::new_armed());
+    assert_eq!(reports.lock().unwrap().len(), 2);
+    assert!(reports.lock().unwrap()[0].starts_with(DEFAULT_DROP_PANIC_MSG));
+
+    // Panic: the hook is still called first.
+    set_leak_policy(LeakPolicy::Panic);
+    assert!(catch_unwind(|| drop(NoDrop::wrap(42))).is_err());
+    assert_eq!(reports.lock().unwrap().len(), 3);
+
+    // A guard leaked inside the hook neither calls it again nor panics.
+    let calls = Arc::new(Mutex::new(0));
+    let counted = Arc::clone(&calls);
+    set_on_leak(move |_| {
+        *counted.lock().unwrap() += 1;
+        drop(NoDrop::wrap("leaked by the hook"));
+    });
+    assert!(catch_unwind(|| drop(NoDrop::wrap(42))).is_err());
+    assert_eq!(*calls.lock().unwrap(), 1);
+
+    // Inert guards do not reach the hook at all.
+    #[cfg(feature = "testing")]
+    {
+        let _inert = no_drop::testing::DisableGuard::new();
+        drop(NoDrop::wrap(42));
+        assert_eq!(*calls.lock().unwrap(), 1);
+    }
+
+    // Without a hook, the default behavior is restored.
+    clear_on_leak();
+    assert!(catch_unwind(|| drop(NoDrop::wrap(42))).is_err());
+    assert_eq!(*calls.lock().unwrap(), 1);
+    assert_eq!(reports.lock().unwrap().len(), 3);
+
+    set_leak_policy(LeakPolicy::LogOnly);
+    drop(NoDrop::wrap(42));
+    set_leak_policy(LeakPolicy::Panic);
+}
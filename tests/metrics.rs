@@ -0,0 +1,30 @@
+//! Tests for the `metrics` feature.
+//!
+//! The count is global, so this file holds a single test, to avoid racing with other tests.
+#![cfg(all(feature = "metrics", feature = "std"))]
+
+use std::panic::catch_unwind;
+
+use no_drop::metrics::{armed_drops, reset_armed_drops};
+use no_drop::rls::{DropGuardEmpty, NoDrop};
+
+#[test]
+fn counts_armed_drops() {
+    reset_armed_drops();
+    assert_eq!(armed_drops(), 0);
+
+    for _ in 0..3 {
+        assert!(catch_unwind(|| drop(NoDrop::wrap(42))).is_err());
+    }
+    assert!(catch_unwind(|| drop(DropGuardEmpty::new_armed())).is_err());
+    assert_eq!(armed_drops(), 4);
+
+    NoDrop::wrap(42).forget();
+    let mut guard = DropGuardEmpty::new_armed();
+    guard.disarm();
+    drop(guard);
+    assert_eq!(armed_drops(), 4);
+
+    reset_armed_drops();
+    assert_eq!(armed_drops(), 0);
+}
@@ -1,25 +1,27 @@
 mod dbg {
+    #[cfg(feature = "std")]
+    use std::collections::HashMap;
 
     use no_drop::dbg::*;
 
     #[test]
     fn consume_returns_value() {
         let value = NoDrop::wrap(42);
-        assert_eq!(value.unwrap(), 42);
+        assert_eq!(value.consume(), 42);
     }
 
     #[test]
-    #[cfg(debug_assertions)]
-    #[should_panic(expected = "Value was dropped without being unwrapped")]
+    #[cfg(any(debug_assertions, feature = "strict-guards"))]
+    #[should_panic(expected = "Value was dropped without being consumed")]
     fn panics_on_drop_in_debug() {
         let _value = NoDrop::wrap(42);
         // Should panic in debug mode
     }
 
     #[test]
-    #[cfg(not(debug_assertions))]
+    #[cfg(not(any(debug_assertions, feature = "strict-guards")))]
     fn does_not_panic_in_release() {
-        let _value = NoDrop::new(42);
+        let _value = NoDrop::wrap(42);
         // Should not panic in release mode
     }
 
@@ -28,6 +30,144 @@ mod dbg {
         let value = NoDrop::new();
         value.forget();
     }
+
+    #[test]
+    fn combinators_match_in_all_builds() {
+        let value = NoDrop::wrap(21).map(|n| n * 2).then(|n| NoDrop::wrap(n + 1));
+        assert_eq!(value.consume_with_guarded(|n| n - 1), 42);
+    }
+
+    #[test]
+    fn value_guard_matches_in_all_builds() {
+        let mut guard = DropGuardEmpty::wrap_armed(42);
+        assert!(guard.disarm());
+        assert_eq!(guard.consume(), 42);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn helpers_match_in_all_builds() {
+        let mut total = 0;
+        let mut callback = NoDrop::wrap(Box::new(|n: i32| total += n) as Box<dyn FnMut(i32)>);
+        callback.call(1);
+        callback.run_final(2);
+        assert_eq!(total, 3);
+
+        let nested = NoDrop::wrap(vec![vec![1, 2], vec![], vec![3]]);
+        assert_eq!(nested.guarded_concat().consume(), vec![1, 2, 3]);
+
+        let values = NoDrop::wrap(vec![1, 2, 3]);
+        assert_eq!(values.into_iter_consuming().collect::<Vec<_>>(), vec![1, 2, 3]);
+
+        let map = NoDrop::wrap(HashMap::from([("a", 1), ("b", 2)]));
+        assert_eq!(map.map_values(|n| n * 2).consume(), HashMap::from([("a", 2), ("b", 4)]));
+
+        let mut map = NoDrop::wrap(HashMap::from([("a", 1)]));
+        map.extend_entries([("b", 2)]);
+        assert_eq!(map.consume(), HashMap::from([("a", 1), ("b", 2)]));
+
+        let mut values = NoDrop::wrap(vec![3, 1, 2, 3, 1]);
+        values.sort_dedup();
+        assert_eq!(values.consume(), vec![1, 2, 3]);
+
+        let values = NoDrop::wrap(vec![10, 20]);
+        assert_eq!(values.iter_enumerate().collect::<Vec<_>>(), vec![(0, &10), (1, &20)]);
+        values.forget();
+
+        let values = NoDrop::wrap(vec![3, 1, 2]);
+        assert_eq!((values.guarded_min(), values.guarded_max()), (Some(&1), Some(&3)));
+        values.forget();
+
+        assert_eq!(NoDrop::wrap(vec![1, 2, 3, 4]).fold(0, |sum, n| sum + n), 10);
+
+        let values = NoDrop::wrap(vec![2, 4, 6]);
+        assert!(values.any(|&n| n == 4));
+        assert!(values.all(|&n| n % 2 == 0));
+        values.forget();
+
+        let values = NoDrop::wrap(vec![1, 2, 3, 4]);
+        assert_eq!(values.count_if(|&n| n % 2 == 0), 2);
+        values.forget();
+
+        let values = NoDrop::wrap(vec![5, 6, 7]);
+        assert_eq!(values.position(|&n| n == 6), Some(1));
+        values.forget();
+
+        let chunks: Vec<_> = NoDrop::wrap(vec![1, 2, 3, 4, 5]).into_chunks(2).map(NoDrop::consume).collect();
+        assert_eq!(chunks, vec![vec![1, 2], vec![3, 4], vec![5]]);
+
+        let (first, rest) = NoDrop::wrap(vec![1, 2, 3]).guarded_split_first().unwrap_or_else(|_| unreachable!());
+        assert_eq!((first, rest.consume()), (1, vec![2, 3]));
+
+        let groups = NoDrop::wrap(vec![1, 2, 3, 4]).group_by_key(|n| n % 2);
+        assert_eq!(groups.consume(), HashMap::from([(1, vec![1, 3]), (0, vec![2, 4])]));
+
+        let mut wrapper = NoDrop::wrap(vec![1, 2, 3, 4]);
+        assert_eq!(wrapper.splice_range(1..3, [20, 30, 40]), vec![2, 3]);
+        assert_eq!(wrapper.consume(), vec![1, 20, 30, 40, 4]);
+
+        let mut elements = NoDrop::wrap([1, 2, 3]).into_elements();
+        assert_eq!(elements.take_element(1), Some(2));
+        assert_eq!(elements.consume_remaining(), [Some(1), None, Some(3)]);
+
+        let mut values = NoDrop::wrap(vec![1, 2, 3]);
+        values.push(4);
+        values.insert(0, 0);
+        assert_eq!((values.len(), values.is_empty(), values.get(1)), (5, false, Some(&1)));
+        assert_eq!((values.pop(), values.remove(0), values.swap_remove(0)), (Some(4), 0, 1));
+        *values.get_mut(0).unwrap() += 10;
+        values.iter_mut().for_each(|n| *n *= 2);
+        assert_eq!(values.iter().copied().collect::<Vec<_>>(), vec![26, 4]);
+        values.chunks_mut(1).for_each(|chunk| chunk[0] /= 2);
+        assert_eq!(values.as_chunks::<2>(), (&[[13, 2]][..], &[][..]));
+        values.reverse();
+        values.dedup_by_key(|n| *n > 0);
+        assert_eq!(values.consume(), vec![2]);
+
+        let mut array = NoDrop::wrap([1, 2, 3]);
+        array.reverse();
+        assert_eq!(array.consume(), [3, 2, 1]);
+
+        let boxed = NoDrop::wrap(vec![1, 2].into_boxed_slice());
+        assert_eq!(boxed.to_vec(), vec![1, 2]);
+        boxed.forget();
+
+        let map = NoDrop::wrap(HashMap::from([("a", 1)]));
+        assert_eq!((map.keys().count(), map.values().sum::<i32>()), (1, 1));
+        assert_eq!((map.contains_key(&"a"), map.get(&"a")), (true, Some(&1)));
+        map.forget();
+
+        let mut iter = NoDrop::wrap(1..4);
+        assert_eq!(iter.by_ref().next(), Some(1));
+        assert_eq!(iter.consume().collect::<Vec<_>>(), vec![2, 3]);
+
+        let mut values = NoDrop::wrap(vec![1, 2, 3]);
+        values.swap(0, 1);
+        assert_eq!(values.consume(), vec![2, 1, 3]);
+
+        let mut option = NoDrop::wrap(Some(1));
+        assert_eq!(option.replace(2), Some(1));
+        assert_eq!(option.consume(), Some(2));
+
+        let mut seen = Vec::new();
+        consume_indexed(vec![NoDrop::wrap('a'), NoDrop::wrap('b')], |index, value| seen.push((index, value)));
+        assert_eq!(seen, vec![(0, 'a'), (1, 'b')]);
+
+        let mut values = NoDrop::wrap(vec![1, 2, 3]);
+        *values.guarded_get_mut(0).unwrap() = 10;
+        assert_eq!((values.guarded_get(0), values.guarded_get(3)), (Some(&10), None));
+        values.forget();
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn msg_combinators_match_in_all_builds() {
+        let value = NoDropMsg::wrap(21, "message").map(|n| n * 2).map_msg(|msg| msg);
+        assert_eq!(value.consume(), 42);
+
+        let value = NoDropMsg::wrap_with(42, || String::from("lazy message"));
+        assert_eq!(value.consume(), 42);
+    }
 }
 
 mod rls {
@@ -36,22 +176,22 @@ mod rls {
     #[test]
     fn consume_returns_value() {
         let value = NoDrop::wrap(42);
-        assert_eq!(value.unwrap(), 42);
+        assert_eq!(value.consume(), 42);
     }
 
     #[test]
-    #[cfg(debug_assertions)]
-    #[should_panic(expected = "Value was dropped without being unwrapped")]
+    #[cfg(any(debug_assertions, feature = "strict-guards"))]
+    #[should_panic(expected = "Value was dropped without being consumed")]
     fn panics_on_drop_in_debug() {
         let _value = NoDrop::wrap(42);
         // Should panic in debug mode
     }
 
     #[test]
-    #[cfg(not(debug_assertions))]
-    #[should_panic(expected = "Value was dropped without being unwrapped")]
+    #[cfg(not(any(debug_assertions, feature = "strict-guards")))]
+    #[should_panic(expected = "Value was dropped without being consumed")]
     fn panics_on_drop_in_release() {
-        let _value = NoDrop::new(42);
+        let _value = NoDrop::wrap(42);
         // Should panic in release mode
     }
 
@@ -0,0 +1,34 @@
+//! Exercises the `core`-only surface of the crate from a `no_std` crate.
+#![no_std]
+
+use no_drop::rls::{DropGuardEmpty, NoDrop, NoDropWith};
+
+#[test]
+fn no_drop_unwrap() {
+    let value = NoDrop::wrap(42);
+    assert_eq!(value.consume(), 42);
+}
+
+#[test]
+fn no_drop_guard_forget() {
+    let guard = NoDrop::new();
+    guard.forget();
+}
+
+#[test]
+#[should_panic(expected = "Value was dropped without being consumed")]
+fn no_drop_panics() {
+    let _value = NoDrop::wrap(42);
+}
+
+#[test]
+fn drop_guard_disarm() {
+    let mut guard = DropGuardEmpty::new_armed();
+    assert!(guard.disarm());
+}
+
+#[test]
+fn no_drop_with_unwrap() {
+    let value = NoDropWith::wrap(42, |_| {});
+    assert_eq!(value.consume(), 42);
+}
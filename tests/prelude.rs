@@ -0,0 +1,38 @@
+//! Tests that the prelude exports the `dbg` types, and supports code generic over them.
+use no_drop::prelude::*;
+
+#[cfg(feature = "alloc")]
+fn consume_all<T>(values: Vec<NoDrop<T>>) -> Vec<T> {
+    values.into_iter().map(NoDrop::consume).collect()
+}
+
+#[test]
+fn prelude_types_are_dbg_types() {
+    let value: no_drop::dbg::NoDrop<i32> = NoDrop::wrap(42);
+    assert_eq!(value.consume(), 42);
+
+    let guard: no_drop::dbg::DropGuardEmpty = DropGuardEmpty::new_disarmed();
+    assert!(matches!(NoDrop::<()>::try_from(guard), Err(GuardError::NotArmed)));
+    assert_eq!(GuardError::from(GuardNotArmed), GuardError::NotArmed);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn generic_over_prelude_types() {
+    let values = vec![NoDrop::wrap(1), 2.no_drop(), NoDrop::wrap(3)];
+    assert_eq!(consume_all(values), vec![1, 2, 3]);
+
+    let mut total = 0;
+    consume_indexed(vec![NoDrop::wrap(10), NoDrop::wrap(20)], |index, value| total += index * value);
+    assert_eq!(total, 20);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn message_types() {
+    let mut guard = DropGuard::new_armed("must be disarmed");
+    guard.disarm();
+
+    let value = NoDropMsg::wrap(42, "must be consumed");
+    assert_eq!(value.consume(), 42);
+}
@@ -0,0 +1,28 @@
+//! Tests for the `strict-guards` feature, which keeps the `dbg` types checked in every build.
+#![cfg(feature = "strict-guards")]
+
+use no_drop::dbg::{DropGuardEmpty, NoDrop};
+
+#[test]
+#[should_panic(expected = "Value was dropped without being consumed")]
+fn no_drop_panics_in_every_build() {
+    drop(NoDrop::wrap(42));
+}
+
+#[test]
+#[should_panic(expected = "Value was dropped without being consumed")]
+fn drop_guard_panics_in_every_build() {
+    drop(DropGuardEmpty::new_armed());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic(expected = "custom message")]
+fn no_drop_msg_panics_in_every_build() {
+    drop(no_drop::dbg::NoDropMsg::wrap(42, "custom message"));
+}
+
+#[test]
+fn consumed_does_not_panic() {
+    assert_eq!(NoDrop::wrap(42).consume(), 42);
+}
@@ -0,0 +1,76 @@
+//! Tests for the `testing` feature.
+#![cfg(feature = "testing")]
+
+use std::panic::catch_unwind;
+
+use no_drop::rls::{DropGuardEmpty, NoDrop};
+use no_drop::testing::{expect_consumed, guards_inert, DisableGuard};
+
+#[test]
+fn armed_drop_inside_scope_does_not_panic() {
+    let _inert = DisableGuard::new();
+    assert!(guards_inert());
+    drop(NoDrop::wrap(42));
+    drop(DropGuardEmpty::new_armed());
+}
+
+#[test]
+fn lazy_message_not_computed_inside_scope() {
+    let _inert = DisableGuard::new();
+    drop(no_drop::rls::NoDropMsg::wrap_with(42, || unreachable!("message computed while inert")));
+}
+
+#[test]
+fn armed_drop_outside_scope_panics() {
+    drop(DisableGuard::new());
+    assert!(!guards_inert());
+    assert!(catch_unwind(|| drop(NoDrop::wrap(42))).is_err());
+}
+
+#[test]
+fn nested_scopes_restore_previous_state() {
+    let outer = DisableGuard::new();
+    {
+        let _inner = DisableGuard::new();
+        assert!(guards_inert());
+    }
+    assert!(guards_inert());
+    drop(outer);
+    assert!(!guards_inert());
+}
+
+#[test]
+fn scope_only_affects_its_thread() {
+    let _inert = DisableGuard::new();
+    let result = std::thread::spawn(|| catch_unwind(|| drop(NoDrop::wrap(42))).is_err()).join();
+    assert!(result.unwrap());
+}
+
+#[test]
+fn expect_consumed_returns_value() {
+    let value = expect_consumed(|| {
+        let first = NoDrop::wrap(20);
+        NoDrop::wrap(first.consume() + 22)
+    });
+    assert_eq!(value, 42);
+    assert_eq!(no_drop::assert_consumed!(NoDrop::wrap("done")), "done");
+}
+
+#[test]
+fn expect_consumed_fails_on_leak() {
+    let payload = catch_unwind(|| {
+        expect_consumed(|| {
+            drop(NoDrop::wrap("leaked"));
+            NoDrop::wrap(42)
+        })
+    })
+    .unwrap_err();
+    let message = payload.downcast_ref::<String>().unwrap();
+    assert!(message.starts_with("expected every guard to be consumed, but one leaked: "), "{message}");
+}
+
+#[test]
+fn expect_consumed_propagates_other_panics() {
+    let payload = catch_unwind(|| expect_consumed::<i32>(|| panic!("unrelated"))).unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"unrelated"));
+}
@@ -0,0 +1,112 @@
+//! Tests for the `tracing` feature.
+//!
+//! Events are captured by a minimal subscriber, installed for the duration of each test.
+#![cfg(feature = "tracing")]
+
+use std::fmt::Debug;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Metadata, Subscriber};
+
+/// A subscriber that records the level and message of every event.
+#[derive(Clone, Default)]
+struct Capture(Arc<Mutex<Vec<(Level, String)>>>);
+
+struct MessageVisitor<'a>(&'a mut String);
+
+impl Visit for MessageVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            *self.0 = format!("{value:?}");
+        }
+    }
+}
+
+impl Subscriber for Capture {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut message = String::new();
+        event.record(&mut MessageVisitor(&mut message));
+        self.0.lock().unwrap().push((*event.metadata().level(), message));
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+/// Runs `f` with a [`Capture`] subscriber installed, returning the captured events.
+fn capture(f: impl FnOnce()) -> Vec<(Level, String)> {
+    let capture = Capture::default();
+    tracing::subscriber::with_default(capture.clone(), f);
+    let events = capture.0.lock().unwrap().clone();
+    events
+}
+
+fn assert_error_event(events: &[(Level, String)], msg: &str) {
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].0, Level::ERROR);
+    assert!(events[0].1.starts_with(msg), "unexpected message: {}", events[0].1);
+    assert!(events[0].1.contains(&format!("(created at {}:", file!())));
+}
+
+#[test]
+fn panicking_drop_emits_error_first() {
+    let events = capture(|| {
+        let result = catch_unwind(AssertUnwindSafe(|| drop(no_drop::rls::NoDrop::wrap(42))));
+        assert!(result.is_err());
+    });
+    assert_error_event(&events, no_drop::rls::DEFAULT_DROP_PANIC_MSG);
+}
+
+#[test]
+fn panicking_msg_drop_emits_error_first() {
+    let events = capture(|| {
+        let result = catch_unwind(AssertUnwindSafe(|| drop(no_drop::rls::NoDropMsg::wrap(42, "custom message"))));
+        assert!(result.is_err());
+    });
+    assert_error_event(&events, "custom message");
+}
+
+#[test]
+fn consumed_emits_nothing() {
+    let events = capture(|| {
+        assert_eq!(no_drop::rls::NoDrop::wrap(42).consume(), 42);
+        assert_eq!(no_drop::log_only::NoDrop::wrap(42).consume(), 42);
+    });
+    assert!(events.is_empty());
+}
+
+#[test]
+fn consume_expect_emits_context() {
+    let events = capture(|| assert_eq!(no_drop::rls::NoDrop::wrap(42).consume_expect("sent to client"), 42));
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].0, Level::DEBUG);
+    assert!(events[0].1.starts_with("sent to client (created at "), "unexpected message: {}", events[0].1);
+}
+
+#[test]
+fn log_only_drop_emits_error_without_panicking() {
+    let events = capture(|| drop(no_drop::log_only::NoDrop::wrap(42)));
+    assert_error_event(&events, no_drop::log_only::DEFAULT_DROP_PANIC_MSG);
+}
+
+#[test]
+fn log_only_msg_drop_emits_error_without_panicking() {
+    let events = capture(|| drop(no_drop::log_only::NoDropMsg::wrap(42, "custom message")));
+    assert_error_event(&events, "custom message");
+}
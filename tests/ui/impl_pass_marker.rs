@@ -0,0 +1,5 @@
+struct Marker;
+
+impl no_drop::markers::PassMarker for Marker {}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use no_drop::must_consume;
+
+#[must_consume]
+struct Resource {
+    #[must_consume]
+    handle: u32,
+}
+
+fn main() {
+    // Marked fields are guarded, so must be constructed with `new`.
+    let resource = Resource { handle: 42 };
+    resource.consume();
+}
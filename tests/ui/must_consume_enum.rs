@@ -0,0 +1,9 @@
+use no_drop::must_consume;
+
+#[must_consume]
+enum Resource {
+    Open(u32),
+    Closed,
+}
+
+fn main() {}
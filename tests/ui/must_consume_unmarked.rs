@@ -0,0 +1,8 @@
+use no_drop::must_consume;
+
+#[must_consume]
+struct Resource {
+    handle: u32,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use no_drop::rls::NoDropMsg;
+
+fn main() {
+    let guard = NoDropMsg::guard("message");
+    let _ = guard.into_message();
+}
@@ -0,0 +1,7 @@
+#![deny(unused_must_use)]
+
+use no_drop::rls::NoDrop;
+
+fn main() {
+    NoDrop::wrap(42);
+}